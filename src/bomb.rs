@@ -0,0 +1,235 @@
+use bevy::prelude::*;
+use bevy::input::keyboard::{KeyboardInput, Key};
+use crate::enemy::{Enemy, EnemyBullet, EnemyKilledEvent, Health, Word};
+use crate::boss::{BossLine, BossParticle};
+use crate::player::{Player, Ship};
+use crate::particles::spawn_explosion;
+use crate::score::ScoreEvent;
+
+pub struct BombPlugin;
+
+impl Plugin for BombPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BombCommand>()
+           .add_event::<BombDetonatedEvent>()
+           .add_systems(Startup, setup_bomb_ui)
+           .add_systems(Update, (
+               bomb_command_system
+                   .before(crate::combat::typing_system)
+                   .before(crate::boss::boss_typing_system)
+                   .in_set(crate::ui::TypingSubmit),
+               detonate_bomb
+                   .after(bomb_command_system)
+                   .before(crate::combat::typing_system),
+               combo_bomb_reward,
+               update_shockwaves,
+               update_bomb_ui,
+           ).run_if(in_state(crate::resources::GameState::Running)));
+    }
+}
+
+// Rotated through one per detonation. These are filtered out of enemy words,
+// so the command can never match anything on screen.
+pub const BOMB_WORDS: [&str; 5] = ["boom", "blast", "nova", "flare", "pulse"];
+
+pub const MAX_BOMBS: u32 = 3;
+const BOMB_RADIUS: f32 = 300.0;
+const BOMB_DAMAGE: i32 = 2;
+const COMBO_PER_BOMB: u32 = 10;
+
+pub fn is_reserved_word(word: &str) -> bool {
+    BOMB_WORDS.iter().any(|w| w.eq_ignore_ascii_case(word))
+}
+
+#[derive(Resource, Default)]
+pub struct BombCommand {
+    pub index: usize,
+    // Combo milestones already paid out, so each one only grants a bomb once
+    combo_rewarded: u32,
+}
+
+impl BombCommand {
+    pub fn word(&self) -> &'static str {
+        BOMB_WORDS[self.index % BOMB_WORDS.len()]
+    }
+
+    fn rotate(&mut self) {
+        self.index = (self.index + 1) % BOMB_WORDS.len();
+    }
+}
+
+// Sent once the command word is typed and a bomb is spent
+#[derive(Event)]
+pub struct BombDetonatedEvent {
+    center: Vec3,
+}
+
+// Anything hostile that a blast simply wipes out
+type Projectiles = Or<(With<EnemyBullet>, With<BossParticle>)>;
+
+#[derive(Component)]
+pub struct Shockwave {
+    timer: Timer,
+    radius: f32,
+}
+
+#[derive(Component)]
+struct BombText;
+
+fn setup_bomb_ui(mut commands: Commands) {
+    commands.spawn((
+        Text::new("Bombs: 1"),
+        TextFont {
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.5, 0.0)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(90.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+        BombText,
+    ));
+}
+
+fn update_bomb_ui(
+    player_query: Query<&Ship, With<Player>>,
+    bomb_command: Res<BombCommand>,
+    mut text_query: Query<&mut Text, With<BombText>>,
+) {
+    if let (Ok(ship), Ok(mut text)) = (player_query.get_single(), text_query.get_single_mut()) {
+        text.0 = format!("Bombs: {}/{}  (type \"{}\")", ship.bombs, MAX_BOMBS, bomb_command.word());
+    }
+}
+
+fn bomb_command_system(
+    mut key_evr: EventReader<KeyboardInput>,
+    mut typing_buffer: ResMut<crate::ui::TypingBuffer>,
+    mut bomb_command: ResMut<BombCommand>,
+    mut player_query: Query<(&mut Ship, &Transform), With<Player>>,
+    boss_line_query: Query<&BossLine>,
+    mut detonated_events: EventWriter<BombDetonatedEvent>,
+) {
+    for ev in key_evr.read() {
+        if !ev.state.is_pressed() || ev.logical_key != Key::Enter || typing_buffer.enter_commits() {
+            continue;
+        }
+
+        let typed = typing_buffer.text.trim();
+        if !typed.eq_ignore_ascii_case(bomb_command.word()) {
+            continue;
+        }
+
+        // A boss line that happens to be the command word should still be typeable
+        if let Ok(boss_line) = boss_line_query.get_single()
+            && let Some(line) = boss_line.lines.get(boss_line.current_line_index)
+            && line.trim().eq_ignore_ascii_case(typed)
+        {
+            continue;
+        }
+
+        typing_buffer.clear();
+
+        let Ok((mut ship, player_transform)) = player_query.get_single_mut() else {
+            continue;
+        };
+
+        if ship.bombs == 0 {
+            println!("No bombs left!");
+            continue;
+        }
+
+        ship.bombs -= 1;
+        detonated_events.send(BombDetonatedEvent { center: player_transform.translation });
+
+        bomb_command.rotate();
+        println!("Smart Bomb! Bombs left: {}", ship.bombs);
+    }
+}
+
+pub fn detonate_bomb(
+    mut commands: Commands,
+    mut events: EventReader<BombDetonatedEvent>,
+    mut enemy_query: Query<(Entity, &Transform, &mut Health, &Word, &Enemy)>,
+    bullet_query: Query<(Entity, &Transform), Projectiles>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut killed_events: EventWriter<EnemyKilledEvent>,
+) {
+    for event in events.read() {
+        let center = event.center;
+
+        for (entity, transform) in bullet_query.iter() {
+            if transform.translation.distance(center) <= BOMB_RADIUS {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+
+        for (entity, transform, mut health, word, enemy) in enemy_query.iter_mut() {
+            if transform.translation.distance(center) > BOMB_RADIUS {
+                continue;
+            }
+
+            health.current -= BOMB_DAMAGE;
+            if health.current <= 0 {
+                score_events.send(ScoreEvent::for_word(transform.translation, word.text.chars().count(), 1, None));
+                spawn_explosion(&mut commands, transform.translation, Color::srgb(1.0, 0.5, 0.0), 15);
+                killed_events.send(EnemyKilledEvent {
+                    archetype: enemy.archetype.clone(),
+                    position: transform.translation,
+                    word: word.text.clone(),
+                    elite: enemy.elite,
+                });
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+
+        spawn_explosion(&mut commands, center, Color::srgb(1.0, 0.8, 0.2), 40);
+        commands.spawn((
+            Transform::from_translation(center),
+            Shockwave {
+                timer: Timer::from_seconds(0.4, TimerMode::Once),
+                radius: BOMB_RADIUS,
+            },
+        ));
+    }
+}
+
+fn combo_bomb_reward(
+    mut player_query: Query<&mut Ship, With<Player>>,
+    mut bomb_command: ResMut<BombCommand>,
+) {
+    if let Ok(mut ship) = player_query.get_single_mut() {
+        let milestone = ship.combo / COMBO_PER_BOMB;
+
+        if milestone > bomb_command.combo_rewarded {
+            ship.bombs = (ship.bombs + milestone - bomb_command.combo_rewarded).min(MAX_BOMBS);
+            println!("Combo {}! Bomb earned: {}", ship.combo, ship.bombs);
+        }
+        bomb_command.combo_rewarded = milestone;
+    }
+}
+
+fn update_shockwaves(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    mut query: Query<(Entity, &Transform, &mut Shockwave)>,
+) {
+    for (entity, transform, mut shockwave) in query.iter_mut() {
+        shockwave.timer.tick(time.delta());
+
+        if shockwave.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let progress = shockwave.timer.fraction();
+        gizmos.circle_2d(
+            transform.translation.truncate(),
+            shockwave.radius * progress,
+            Color::srgb(1.0, 0.7, 0.2).with_alpha(1.0 - progress),
+        );
+    }
+}
@@ -8,12 +8,14 @@ impl Plugin for BossPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Update, (
             boss_particle_system,
-            boss_typing_system,
+            boss_typing_system.in_set(crate::ui::TypingSubmit),
             boss_particle_movement,
             boss_collision_system,
             boss_health_bar_update,
             boss_player_collision,
+            apply_boss_damage.after(boss_typing_system),
         ).run_if(in_state(crate::resources::GameState::Running)))
+        .add_event::<BossDamageEvent>()
         .add_systems(OnEnter(crate::resources::GameState::BossWarning), setup_boss_warning)
         .add_systems(Update, boss_warning_countdown.run_if(in_state(crate::resources::GameState::BossWarning)))
         .add_systems(OnExit(crate::resources::GameState::BossWarning), cleanup_boss_warning);
@@ -41,6 +43,12 @@ pub struct BossParticle {
 #[derive(Component)]
 pub struct BossHealthBar;
 
+// One point of damage clears the boss's current line
+#[derive(Event)]
+pub struct BossDamageEvent {
+    pub amount: usize,
+}
+
 #[derive(Component)]
 pub struct BossHealthFill;
 
@@ -434,12 +442,12 @@ fn boss_particle_movement(
 
 fn boss_collision_system(
     mut commands: Commands,
-    mut player_query: Query<(&Transform, &mut crate::player::Ship), With<Player>>,
+    player_query: Query<(&Transform, &crate::player::Ship), With<Player>>,
     particle_query: Query<(Entity, &Transform), With<BossParticle>>,
-    mut next_state: ResMut<NextState<crate::resources::GameState>>,
+    mut damage_events: EventWriter<crate::damage::PlayerDamageEvent>,
 ) {
-    if let Ok((player_transform, mut ship)) = player_query.get_single_mut() {
-        if !ship.invulnerability_timer.finished() {
+    if let Ok((player_transform, ship)) = player_query.get_single() {
+        if ship.is_invulnerable() {
             return;
         }
         
@@ -448,19 +456,11 @@ fn boss_collision_system(
             if distance < 30.0 {
                 commands.entity(particle_entity).despawn_recursive();
                 
-                ship.hp -= 1;
-                ship.combo = 0;
-                
-                spawn_explosion(&mut commands, particle_transform.translation, Color::srgb(1.0, 0.0, 0.0), 10);
-                
-                println!("Hit by Boss Particle! HP: {}", ship.hp);
-                
-                if ship.hp <= 0 {
-                    println!("Game Over!");
-                    next_state.set(crate::resources::GameState::GameOver);
-                }
-                
-                ship.invulnerability_timer = Timer::from_seconds(0.5, TimerMode::Once);
+                damage_events.send(crate::damage::PlayerDamageEvent {
+                    source: crate::damage::DamageSource::BossParticle,
+                    amount: 1,
+                    position: particle_transform.translation,
+                });
                 
                 break;
             }
@@ -485,17 +485,23 @@ fn boss_health_bar_update(
     }
 }
 
+// Everything typing a boss line can announce
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct BossLineEvents<'w> {
+    boss_damage: EventWriter<'w, BossDamageEvent>,
+    correct_word: EventWriter<'w, crate::combat::CorrectWordEvent>,
+    score: EventWriter<'w, crate::score::ScoreEvent>,
+    mistake: EventWriter<'w, crate::mistake::MistakeEvent>,
+}
+
 pub fn boss_typing_system(
     mut commands: Commands,
     mut key_evr: EventReader<bevy::input::keyboard::KeyboardInput>,
-    mut boss_query: Query<(Entity, &mut Boss, &mut BossLine, &Children, &Transform)>,
-    mut text_query: Query<(&mut Text2d, &mut TextFont)>,
+    boss_query: Query<(&BossLine, &Transform), With<Boss>>,
     mut typing_buffer: ResMut<crate::ui::TypingBuffer>,
-    mut content_manager: ResMut<crate::resources::ContentManager>,
-    mut wave: ResMut<crate::resources::Wave>,
-    difficulty: Res<crate::resources::Difficulty>,
-    health_bar_query: Query<Entity, With<BossHealthBar>>,
-    mut player_query: Query<&mut crate::player::Ship, With<Player>>,
+    mut player_query: Query<(&mut crate::player::Ship, &Transform), With<Player>>,
+    mut judge: crate::combat::WordJudge,
+    mut events: BossLineEvents,
 ) {
     use bevy::input::keyboard::Key;
     
@@ -505,74 +511,132 @@ pub fn boss_typing_system(
         }
         
         if let Key::Enter = ev.logical_key {
+            if typing_buffer.enter_commits() {
+                continue;
+            }
+            
             let typed_text = typing_buffer.text.trim();
             
             if typed_text.is_empty() {
                 continue;
             }
             
-            if let Ok((boss_entity, mut boss, mut boss_line, children, boss_transform)) = boss_query.get_single_mut() {
+            if !boss_query.is_empty() && player_query.get_single().is_ok_and(|(ship, _)| ship.is_locked_out()) {
+                println!("Weapons locked out!");
+                typing_buffer.clear();
+                continue;
+            }
+            
+            if let Ok((boss_line, boss_transform)) = boss_query.get_single() {
                 let current_line = boss_line.lines[boss_line.current_line_index].clone();
                 
-                let matches = match *difficulty {
-                    crate::resources::Difficulty::Easy => {
-                        current_line.to_lowercase() == typed_text.to_lowercase()
-                    }
-                    crate::resources::Difficulty::Hard => {
-                        current_line == typed_text
-                    }
-                };
+                let typed_normalized = judge.normalize(typed_text);
+                let line_normalized = judge.normalize(&current_line);
+                let matches = typed_normalized == line_normalized;
+                judge.record(&typed_normalized, &line_normalized);
                 
                 if matches {
                     println!("Boss line typed correctly!");
-                    boss.health -= 1;
-                    boss_line.current_line_index += 1;
-                    
-                    if let Ok(mut ship) = player_query.get_single_mut() {
-                        let line_len = current_line.len() as u32;
-                        ship.score += line_len * 100 * (ship.combo + 1);
-                        ship.combo += 1;
-                    }
                     
-                    let children_vec: Vec<Entity> = children.iter().copied().collect();
-                    if !children_vec.is_empty() {
-                        if let Ok((mut text, mut font)) = text_query.get_mut(children_vec[0]) {
-                            if boss_line.current_line_index < boss_line.lines.len() {
-                                **text = boss_line.lines[boss_line.current_line_index].clone();
-                                let line_len = text.len().max(1) as f32;
-                                font.font_size = (50.0 * (20.0 / line_len).min(1.0)).clamp(20.0, 50.0);
-                            } else {
-                                **text = "DEFEATED!".to_string();
-                            }
+                    if let Ok((mut ship, player_transform)) = player_query.get_single_mut() {
+                        let mut crit_multiplier = 1.0;
+                        if typing_buffer.is_clean() {
+                            ship.perfect_streak += 1;
+                            crit_multiplier = crate::combat::CRIT_SCORE_MULTIPLIER;
+                            crate::particles::spawn_crit_burst(&mut commands, player_transform.translation);
+                            println!("Critical! Perfect streak: {}", ship.perfect_streak);
+                        } else {
+                            ship.perfect_streak = 0;
                         }
-                    }
-                    
-                    if boss.health == 0 {
-                        println!("Boss Defeated!");
-                        
-                        spawn_explosion(&mut commands, boss_transform.translation, Color::srgb(0.8, 0.0, 0.8), 30);
-                        
-                        content_manager.next_paragraph();
                         
-                        commands.entity(boss_entity).despawn_recursive();
+                        events.score.send(
+                            crate::score::ScoreEvent::for_word(
+                                boss_transform.translation,
+                                current_line.chars().count(),
+                                ship.combo_tier().multiplier(),
+                                typing_buffer.typing_secs(judge.now()),
+                            )
+                            .with_modifier(crit_multiplier * ship.perfect_multiplier()),
+                        );
+                        ship.add_combo();
                         
-                        for entity in health_bar_query.iter() {
-                            commands.entity(entity).despawn_recursive();
-                        }
-                        
-                        wave.enemies_remaining = 0;
-                        println!("Boss defeated! Wave progression will continue...");
+                        events.correct_word.send(crate::combat::CorrectWordEvent {
+                            player_position: player_transform.translation,
+                            chars: current_line.chars().count(),
+                        });
                     }
+                    
+                    events.boss_damage.send(BossDamageEvent { amount: 1 });
                 } else {
                     println!("Wrong line typed!");
+                    if let Ok((mut ship, player_transform)) = player_query.get_single_mut() {
+                        ship.perfect_streak = 0;
+                        events.mistake.send(crate::mistake::MistakeEvent {
+                            position: player_transform.translation,
+                        });
+                    }
                 }
                 
-                typing_buffer.text.clear();
+                typing_buffer.clear();
             }
         }
     }
 }
 
+fn apply_boss_damage(
+    mut commands: Commands,
+    mut events: EventReader<BossDamageEvent>,
+    mut boss_query: Query<(Entity, &mut Boss, &mut BossLine, &Children, &Transform)>,
+    mut text_query: Query<(&mut Text2d, &mut TextFont)>,
+    mut content_manager: ResMut<crate::resources::ContentManager>,
+    mut wave: ResMut<crate::resources::Wave>,
+    health_bar_query: Query<Entity, With<BossHealthBar>>,
+) {
+    for event in events.read() {
+        let Ok((boss_entity, mut boss, mut boss_line, children, boss_transform)) = boss_query.get_single_mut() else {
+            continue;
+        };
+        
+        // Already defeated this frame; the despawn just hasn't applied yet
+        if boss.health == 0 {
+            continue;
+        }
+        
+        let amount = event.amount.min(boss.health);
+        boss.health -= amount;
+        boss_line.current_line_index += amount;
+        
+        if let Some(&label) = children.first()
+            && let Ok((mut text, mut font)) = text_query.get_mut(label)
+        {
+            if boss_line.current_line_index < boss_line.lines.len() {
+                **text = boss_line.lines[boss_line.current_line_index].clone();
+                let line_len = text.len().max(1) as f32;
+                font.font_size = (50.0 * (20.0 / line_len).min(1.0)).clamp(20.0, 50.0);
+            } else {
+                **text = "DEFEATED!".to_string();
+            }
+        }
+        
+        if boss.health == 0 {
+            println!("Boss Defeated!");
+            
+            spawn_explosion(&mut commands, boss_transform.translation, Color::srgb(0.8, 0.0, 0.8), 30);
+            
+            content_manager.next_paragraph();
+            
+            commands.entity(boss_entity).despawn_recursive();
+            
+            for entity in health_bar_query.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            
+            wave.enemies_remaining = 0;
+            println!("Boss defeated! Wave progression will continue...");
+        }
+    }
+}
+
 fn setup_boss_warning(
     mut commands: Commands,
     mut player_query: Query<&mut Transform, With<Player>>,
@@ -775,13 +839,12 @@ fn cleanup_boss_warning(
 }
 
 fn boss_player_collision(
-    mut commands: Commands,
-    mut player_query: Query<(&mut Transform, &mut crate::player::Ship), With<Player>>,
+    mut player_query: Query<(&mut Transform, &crate::player::Ship), With<Player>>,
     boss_query: Query<&Transform, (With<Boss>, Without<Player>)>,
-    mut next_state: ResMut<NextState<crate::resources::GameState>>,
+    mut damage_events: EventWriter<crate::damage::PlayerDamageEvent>,
 ) {
-    if let Ok((mut player_transform, mut ship)) = player_query.get_single_mut() {
-        if !ship.invulnerability_timer.finished() {
+    if let Ok((mut player_transform, ship)) = player_query.get_single_mut() {
+        if ship.is_invulnerable() {
             return;
         }
         
@@ -798,27 +861,13 @@ fn boss_player_collision(
                 // Apply knockback
                 player_transform.translation += knockback_dir * knockback_distance;
                 
-                // Deal damage
-                ship.hp -= 1;
-                ship.combo = 0;
-                
-                // Spawn explosion effect at collision point
-                spawn_explosion(
-                    &mut commands,
-                    player_transform.translation,
-                    Color::srgb(0.8, 0.0, 0.8),
-                    15
-                );
-                
-                println!("Hit by Boss! Knocked back! HP: {}", ship.hp);
-                
-                if ship.hp <= 0 {
-                    println!("Game Over!");
-                    next_state.set(crate::resources::GameState::GameOver);
-                }
+                damage_events.send(crate::damage::PlayerDamageEvent {
+                    source: crate::damage::DamageSource::BossContact,
+                    amount: 1,
+                    position: player_transform.translation,
+                });
                 
-                // Apply invulnerability frames
-                ship.invulnerability_timer = Timer::from_seconds(0.5, TimerMode::Once);
+                println!("Knocked back by Boss!");
             }
         }
     }
@@ -1,58 +1,289 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 use crate::enemy::{Enemy, Word};
 use crate::player::{Player, Ship};
-use crate::particles::{spawn_blade_trail, spawn_explosion, spawn_laser_hit, spawn_error_particles};
+use crate::score::ScoreEvent;
+use crate::damage::{DamageSource, PlayerDamageEvent};
+use crate::particles::{spawn_blade_trail, spawn_chain_arc, spawn_explosion, spawn_laser_hit, spawn_error_particles, spawn_crit_burst};
 
 pub struct CombatPlugin;
 
 impl Plugin for CombatPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (
+        app.init_resource::<WeaponRegistry>()
+           .add_event::<CorrectWordEvent>()
+           .add_systems(Update, (
             weapon_switching,
-            typing_system,
+            bullet_typing_system
+                .before(typing_system)
+                .before(crate::boss::boss_typing_system)
+                .in_set(crate::ui::TypingSubmit),
+            typing_system
+                .in_set(crate::ui::TypingSubmit)
+                .run_if(not(any_with_component::<crate::boss::Boss>)),
             collision_system,
+            parry_system.after(typing_system).after(crate::boss::boss_typing_system),
+            reflected_bullet_movement,
+            reflected_bullet_collision,
         ).run_if(in_state(crate::resources::GameState::Running)));
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum Weapon {
-    #[default]
-    Blade,
-    Laser,
+// Extra score for a word typed without a backspace or wrong key
+pub const CRIT_SCORE_MULTIPLIER: f32 = 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum MovementEffect {
+    None,
+    Teleport,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MissPenalty {
+    pub error_particles: bool,
+    #[serde(default)]
+    pub heat: f32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WeaponEffect {
+    pub color: (f32, f32, f32),
+    pub kill_particles: i32,
+    pub trail: bool,
+}
+
+impl WeaponEffect {
+    pub fn color(&self) -> Color {
+        Color::srgb(self.color.0, self.color.1, self.color.2)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChainDef {
+    pub max_targets: usize,
+    pub radius: f32,
+    pub damage: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ParryDef {
+    pub radius: f32,
+    pub perfect_radius: f32,
+    pub speed_multiplier: f32,
+    pub damage: i32,
+    pub perfect_bonus: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WeaponDef {
+    pub name: String,
+    pub damage: i32,
+    pub score_multiplier: f32,
+    pub movement: MovementEffect,
+    pub invulnerability: f32,
+    pub miss_penalty: MissPenalty,
+    pub effect: WeaponEffect,
+    #[serde(default)]
+    pub chain: Option<ChainDef>,
+    #[serde(default)]
+    pub parry: Option<ParryDef>,
+    #[serde(default)]
+    pub energy_cost: f32,
+    #[serde(default)]
+    pub crit_damage: i32,
+}
+
+impl WeaponDef {
+    pub fn uses_energy(&self) -> bool {
+        self.energy_cost > 0.0 || self.miss_penalty.heat > 0.0
+    }
+    
+    // Chained targets must always take less than the one that was typed
+    pub fn chain_is_weaker(&self) -> bool {
+        self.chain.as_ref().is_none_or(|chain| chain.damage < self.damage)
+    }
+}
+
+// Sent whenever a submission hits its target, enemy word or boss line alike
+#[derive(Event)]
+pub struct CorrectWordEvent {
+    pub player_position: Vec3,
+    pub chars: usize,
+}
+
+#[derive(Component)]
+pub struct ReflectedBullet {
+    pub velocity: Vec2,
+    pub damage: i32,
+}
+
+const DEFAULT_WEAPONS: &str = include_str!("../data/weapons.ron");
+
+#[derive(Resource)]
+pub struct WeaponRegistry {
+    pub weapons: Vec<WeaponDef>,
+}
+
+impl Default for WeaponRegistry {
+    fn default() -> Self {
+        Self::load_from_file()
+    }
+}
+
+impl WeaponRegistry {
+    pub fn load_from_file() -> Self {
+        let path = "data/weapons.ron";
+        
+        let weapons = match std::fs::read_to_string(path) {
+            Ok(content) => match ron::from_str::<Vec<WeaponDef>>(&content) {
+                Ok(weapons) => match Self::problem(&weapons) {
+                    Some(problem) => {
+                        println!("Warning: {} in {}, using defaults", problem, path);
+                        Self::default_weapons()
+                    }
+                    None => weapons,
+                },
+                Err(e) => {
+                    println!("Warning: Failed to parse {}: {}, using defaults", path, e);
+                    Self::default_weapons()
+                }
+            },
+            Err(_) => {
+                println!("Warning: No weapon file found, using default weapons");
+                Self::default_weapons()
+            }
+        };
+        
+        println!("Loaded {} weapons", weapons.len());
+        
+        Self { weapons }
+    }
+    
+    // The shipped file, built in so a broken or missing copy still has something to fall back on
+    fn default_weapons() -> Vec<WeaponDef> {
+        let weapons: Vec<WeaponDef> = ron::from_str(DEFAULT_WEAPONS).expect("built-in weapons.ron should parse");
+        if let Some(problem) = Self::problem(&weapons) {
+            panic!("built-in weapons.ron is unusable: {}", problem);
+        }
+        weapons
+    }
+    
+    // Why a weapon list can't be used as is, checked for the built-in one too
+    fn problem(weapons: &[WeaponDef]) -> Option<String> {
+        if weapons.is_empty() {
+            return Some("No weapons defined".to_string());
+        }
+        weapons
+            .iter()
+            .find(|weapon| !weapon.chain_is_weaker())
+            .map(|weapon| format!("{} chain damage must be lower than its damage", weapon.name))
+    }
+    
+    pub fn get(&self, index: usize) -> &WeaponDef {
+        &self.weapons[index % self.weapons.len()]
+    }
 }
 
 fn weapon_switching(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut query: Query<&mut Ship, With<Player>>,
+    weapons: Res<WeaponRegistry>,
 ) {
     if keyboard_input.just_pressed(KeyCode::Tab) {
         let mut ship = query.single_mut();
-        ship.current_weapon = match ship.current_weapon {
-            Weapon::Blade => Weapon::Laser,
-            Weapon::Laser => Weapon::Blade,
-        };
-        println!("Switched to {:?}", ship.current_weapon);
+        ship.current_weapon = (ship.current_weapon + 1) % weapons.weapons.len();
+        println!("Switched to {}", weapons.get(ship.current_weapon).name);
     }
 }
 
 use bevy::input::keyboard::{KeyboardInput, Key};
+use bevy::ecs::system::SystemParam;
+
+// The weapon being fired and everything a submission can announce
+#[derive(SystemParam)]
+pub struct WeaponFire<'w> {
+    weapons: Res<'w, WeaponRegistry>,
+    correct_word: EventWriter<'w, CorrectWordEvent>,
+    score: EventWriter<'w, ScoreEvent>,
+    mistake: EventWriter<'w, crate::mistake::MistakeEvent>,
+    killed: EventWriter<'w, crate::enemy::EnemyKilledEvent>,
+}
 
-fn typing_system(
+type TypedEnemy = (
+    Entity,
+    &'static mut Word,
+    &'static mut crate::enemy::Health,
+    &'static Children,
+    &'static Transform,
+    &'static Enemy,
+    Option<&'static crate::enemy::EnemyShield>,
+);
+type EnemyLabel = (With<Text2d>, Without<Enemy>, Without<Player>);
+type TypingPlayer = (With<Player>, Without<Enemy>, Without<Text2d>);
+
+// Word labels, recolored and grown to show how a hit landed
+#[derive(SystemParam)]
+pub struct EnemyLabels<'w, 's> {
+    colors: Query<'w, 's, &'static mut TextColor>,
+    transforms: Query<'w, 's, &'static mut Transform, EnemyLabel>,
+}
+
+impl EnemyLabels<'_, '_> {
+    fn flash(&mut self, children: &[Entity], color: Color, scale: f32) {
+        for &child in children {
+            if let Ok(mut text_color) = self.colors.get_mut(child) {
+                text_color.0 = color;
+            }
+            if let Ok(mut text_transform) = self.transforms.get_mut(child) {
+                text_transform.scale *= scale;
+            }
+        }
+    }
+}
+
+// Enemies a word can hit, along with their labels
+#[derive(SystemParam)]
+pub struct EnemyTargets<'w, 's> {
+    enemies: Query<'w, 's, TypedEnemy>,
+    labels: EnemyLabels<'w, 's>,
+}
+
+// How a submission is compared against the words on screen, and where its keystrokes get tallied
+#[derive(SystemParam)]
+pub struct WordJudge<'w> {
+    difficulty: Res<'w, crate::resources::Difficulty>,
+    settings: Res<'w, crate::resources::GameSettings>,
+    finger_stats: ResMut<'w, crate::keyboard::FingerStats>,
+    time: Res<'w, Time>,
+}
+
+impl WordJudge<'_> {
+    // Easy ignores case, so both sides get lowercased before comparing
+    pub fn normalize(&self, text: &str) -> String {
+        match *self.difficulty {
+            crate::resources::Difficulty::Easy => text.to_lowercase(),
+            crate::resources::Difficulty::Hard => text.to_string(),
+        }
+    }
+
+    pub fn record(&mut self, typed: &str, expected: &str) {
+        self.finger_stats.record_word(self.settings.keyboard_layout, typed, expected);
+    }
+
+    pub fn now(&self) -> f32 {
+        self.time.elapsed_secs()
+    }
+}
+
+pub fn typing_system(
     mut commands: Commands,
     mut key_evr: EventReader<KeyboardInput>,
-    mut enemy_query: Query<(Entity, &mut Word, &mut crate::enemy::Health, &Children, &Transform, Option<&crate::enemy::ShootingEnemy>), With<Enemy>>,
-    mut text_color_query: Query<&mut TextColor>,
-    mut text_transform_query: Query<&mut Transform, (With<Text2d>, Without<Enemy>, Without<Player>)>,
-    mut player_query: Query<(Entity, &mut Ship, &mut Transform), (With<Player>, Without<Enemy>, Without<Text2d>)>,
+    mut targets: EnemyTargets,
+    mut player_query: Query<(Entity, &mut Ship, &mut Transform), TypingPlayer>,
     mut typing_buffer: ResMut<crate::ui::TypingBuffer>,
-    difficulty: Res<crate::resources::Difficulty>,
-    boss_query: Query<Entity, With<crate::boss::Boss>>,
+    mut judge: WordJudge,
+    mut fire: WeaponFire,
 ) {
-    if !boss_query.is_empty() {
-        return;
-    }
-    
     let (_player_entity, mut ship, mut player_transform) = player_query.single_mut();
 
     for ev in key_evr.read() {
@@ -61,17 +292,39 @@ fn typing_system(
         }
         
         if let Key::Enter = ev.logical_key {
+            // Enter confirms the IME composition rather than submitting
+            if typing_buffer.enter_commits() {
+                continue;
+            }
+            
             let typed_word = typing_buffer.text.trim();
             
             if typed_word.is_empty() {
                 continue;
             }
             
+            let weapon = fire.weapons.get(ship.current_weapon);
+            
+            if ship.is_locked_out() {
+                spawn_error_particles(&mut commands, player_transform.translation);
+                println!("Weapons locked out!");
+                typing_buffer.clear();
+                continue;
+            }
+            
+            if weapon.uses_energy() && ship.is_overheated() {
+                spawn_error_particles(&mut commands, player_transform.translation);
+                println!("{} overheated!", weapon.name);
+                typing_buffer.clear();
+                continue;
+            }
+            
             let mut hit_any = false;
             let mut actions = Vec::new();
+            let typed_normalized = judge.normalize(typed_word);
 
-            for (entity, word, health, children, enemy_transform, _is_shooting) in enemy_query.iter_mut() {
-                let matches = match *difficulty {
+            for (entity, word, _health, children, enemy_transform, _enemy, _shield) in targets.enemies.iter_mut() {
+                let matches = match *judge.difficulty {
                     crate::resources::Difficulty::Easy => {
                         word.text.to_lowercase() == typed_word.to_lowercase()
                     }
@@ -84,129 +337,400 @@ fn typing_system(
                     hit_any = true;
                     let children_vec: Vec<Entity> = children.iter().copied().collect();
                     let enemy_pos = enemy_transform.translation;
-                    let current_health = health.current;
                     
-                    actions.push((entity, children_vec, enemy_pos, ship.current_weapon, current_health));
+                    actions.push((entity, children_vec, enemy_pos, true));
                     break;
                 }
             }
             
-            for (entity, children_vec, enemy_pos, weapon, _current_health) in actions {
-                match weapon {
-                    Weapon::Blade => {
-                        for &child in children_vec.iter() {
-                            if let Ok(mut text_color) = text_color_query.get_mut(child) {
-                                text_color.0 = Color::srgb(0.0, 1.0, 0.0);
-                            }
-                            if let Ok(mut text_transform) = text_transform_query.get_mut(child) {
-                                text_transform.scale = Vec3::splat(0.08);
-                            }
-                        }
-                        
-                        let word_len = typing_buffer.text.trim().len() as u32;
-                        ship.score += word_len * 100 * (ship.combo + 1);
-                        ship.combo += 1;
-                        
-                        let start_pos = player_transform.translation;
-                        spawn_blade_trail(&mut commands, start_pos, enemy_pos);
-                        
+            // Chain weapons arc from the target to its nearest neighbours, hop by hop
+            if let (Some(chain), Some(&(primary, _, primary_pos, _))) = (&weapon.chain, actions.first()) {
+                let mut visited = vec![primary];
+                let mut arc_from = primary_pos;
+                
+                for _ in 0..chain.max_targets {
+                    let next = targets.enemies
+                        .iter()
+                        .filter(|(e, ..)| !visited.contains(e))
+                        .map(|(e, _, _, children, t, ..)| (e, children, t.translation, t.translation.distance(arc_from)))
+                        .filter(|(.., dist)| *dist <= chain.radius)
+                        .min_by(|a, b| a.3.total_cmp(&b.3));
+                    
+                    let Some((entity, children, pos, _)) = next else {
+                        break;
+                    };
+                    
+                    spawn_chain_arc(&mut commands, arc_from, pos);
+                    visited.push(entity);
+                    actions.push((entity, children.iter().copied().collect(), pos, false));
+                    arc_from = pos;
+                }
+            }
+            
+            let on_screen: Vec<String> = targets.enemies.iter().map(|(_, word, ..)| judge.normalize(&word.text)).collect();
+            if let Some(expected) = crate::keyboard::closest_target(&typed_normalized, on_screen.iter().map(|s| s.as_str())) {
+                judge.record(&typed_normalized, expected);
+            }
+            
+            // A critical hit is a word typed start to finish without a backspace or wrong key
+            let critical = hit_any && typing_buffer.is_clean();
+            let typing_secs = typing_buffer.typing_secs(judge.now());
+            
+            if hit_any {
+                fire.correct_word.send(CorrectWordEvent {
+                    player_position: player_transform.translation,
+                    chars: typed_word.chars().count(),
+                });
+                ship.drain_energy(weapon.energy_cost);
+                
+                if critical {
+                    ship.perfect_streak += 1;
+                    println!("Critical! Perfect streak: {}", ship.perfect_streak);
+                } else {
+                    ship.perfect_streak = 0;
+                }
+            }
+            
+            for (entity, children_vec, enemy_pos, is_primary) in actions {
+                let Ok((_, mut word, mut health, _, _, enemy, shield)) = targets.enemies.get_mut(entity) else {
+                    continue;
+                };
+                
+                if shield.is_some() {
+                    commands.entity(entity).remove::<crate::enemy::EnemyShield>();
+                    spawn_laser_hit(&mut commands, enemy_pos);
+                    println!("{} blocked by a shield!", weapon.name);
+                    continue;
+                }
+                
+                // Armor soaks the whole hit; only the last word can do damage.
+                // Chain arcs bounce off, since their words were never typed
+                if !is_primary && !word.queue.is_empty() {
+                    spawn_laser_hit(&mut commands, enemy_pos);
+                    continue;
+                }
+                
+                if word.strip_layer() {
+                    targets.labels.flash(&children_vec, Color::srgb(0.6, 0.8, 1.0), 1.2);
+                    
+                    spawn_laser_hit(&mut commands, enemy_pos);
+                    ship.add_combo();
+                    
+                    println!("{} stripped armor! Layers left: {}", weapon.name, word.queue.len());
+                    continue;
+                }
+                
+                // Upgrades only sharpen the typed hit, so arcs stay weaker than it
+                health.current -= match (&weapon.chain, is_primary) {
+                    (Some(chain), false) => chain.damage,
+                    _ => weapon.damage + ship.damage_bonus,
+                };
+                
+                if critical && is_primary {
+                    health.current -= weapon.crit_damage;
+                    spawn_crit_burst(&mut commands, enemy_pos);
+                }
+                
+                if health.current <= 0 {
+                    targets.labels.flash(&children_vec, Color::srgb(0.0, 1.0, 0.0), 1.6);
+                    
+                    let crit_multiplier = if critical { CRIT_SCORE_MULTIPLIER } else { 1.0 };
+                    fire.score.send(
+                        ScoreEvent::for_word(enemy_pos, word.text.chars().count(), ship.combo_tier().multiplier(), typing_secs)
+                            .with_base(enemy.score_value)
+                            .with_modifier(weapon.score_multiplier * crit_multiplier * ship.perfect_multiplier()),
+                    );
+                    ship.add_combo();
+                    
+                    if is_primary && weapon.effect.trail {
+                        spawn_blade_trail(&mut commands, player_transform.translation, enemy_pos);
+                    }
+                    
+                    if is_primary && weapon.movement == MovementEffect::Teleport {
                         player_transform.translation = enemy_pos;
-                        ship.invulnerability_timer = Timer::from_seconds(0.15, TimerMode::Once);
-                        
-                        spawn_explosion(&mut commands, enemy_pos, Color::srgb(0.0, 1.0, 0.5), 20);
-                        
-                        println!("Blade Slide Kill!");
-                        commands.entity(entity).despawn_recursive();
                     }
-                    Weapon::Laser => {
-                        if let Ok((_, _, mut health, _, _, _)) = enemy_query.get_mut(entity) {
-                            health.current -= 1;
-                            
-                            if health.current <= 0 {
-                                for &child in children_vec.iter() {
-                                    if let Ok(mut text_color) = text_color_query.get_mut(child) {
-                                        text_color.0 = Color::srgb(0.0, 1.0, 0.0);
-                                    }
-                                    if let Ok(mut text_transform) = text_transform_query.get_mut(child) {
-                                        text_transform.scale = Vec3::splat(0.08);
-                                    }
-                                }
-                                
-                                let word_len = typing_buffer.text.trim().len() as u32;
-                                ship.score += word_len * 100 * (ship.combo + 1);
-                                ship.combo += 1;
-                                ship.invulnerability_timer = Timer::from_seconds(0.15, TimerMode::Once);
-                                
-                                spawn_explosion(&mut commands, enemy_pos, Color::srgb(0.0, 0.8, 1.0), 15);
-                                
-                                println!("Laser Kill!");
-                                commands.entity(entity).despawn_recursive();
-                            } else {
-                                for &child in children_vec.iter() {
-                                    if let Ok(mut text_color) = text_color_query.get_mut(child) {
-                                        text_color.0 = Color::srgb(1.0, 1.0, 0.0);
-                                    }
-                                    if let Ok(mut text_transform) = text_transform_query.get_mut(child) {
-                                        text_transform.scale = Vec3::splat(0.06);
-                                    }
-                                }
-                                
-                                spawn_laser_hit(&mut commands, enemy_pos);
-                                
-                                println!("Laser Hit! Enemy HP: {}", health.current);
-                            }
-                        }
+                    
+                    if is_primary && weapon.invulnerability > 0.0 {
+                        ship.grant_invulnerability(weapon.invulnerability);
                     }
+                    
+                    let particles = weapon.effect.kill_particles as f32 * ship.combo_tier().particle_scale();
+                    spawn_explosion(&mut commands, enemy_pos, weapon.effect.color(), particles as i32);
+                    
+                    fire.killed.send(crate::enemy::EnemyKilledEvent {
+                        archetype: enemy.archetype.clone(),
+                        position: enemy_pos,
+                        word: word.text.clone(),
+                        elite: enemy.elite,
+                    });
+                    
+                    println!("{} Kill!", weapon.name);
+                    commands.entity(entity).despawn_recursive();
+                } else {
+                    targets.labels.flash(&children_vec, Color::srgb(1.0, 1.0, 0.0), 1.2);
+                    
+                    spawn_laser_hit(&mut commands, enemy_pos);
+                    
+                    println!("{} Hit! Enemy HP: {}", weapon.name, health.current);
                 }
             }
             
             if !hit_any {
                 println!("Mistake!");
-                ship.combo = 0;
+                ship.perfect_streak = 0;
+                fire.mistake.send(crate::mistake::MistakeEvent {
+                    position: player_transform.translation,
+                });
                 
-                match ship.current_weapon {
-                    Weapon::Blade => {
-                        println!("Blade Parry!");
-                    }
-                    Weapon::Laser => {
-                        spawn_error_particles(&mut commands, player_transform.translation);
-                        println!("Laser Explode!");
-                    }
+                if weapon.miss_penalty.error_particles {
+                    spawn_error_particles(&mut commands, player_transform.translation);
+                    println!("{} Explode!", weapon.name);
                 }
+                
+                ship.drain_energy(weapon.miss_penalty.heat);
             }
             
-            typing_buffer.text.clear();
+            typing_buffer.clear();
         }
     }
 }
 
+fn bullet_typing_system(
+    mut commands: Commands,
+    mut key_evr: EventReader<KeyboardInput>,
+    mut typing_buffer: ResMut<crate::ui::TypingBuffer>,
+    bullet_query: Query<(Entity, &Transform, &crate::enemy::EnemyBullet)>,
+    difficulty: Res<crate::resources::Difficulty>,
+) {
+    for ev in key_evr.read() {
+        if !ev.state.is_pressed() || ev.logical_key != Key::Enter || typing_buffer.enter_commits() {
+            continue;
+        }
+        
+        let typed = typing_buffer.text.trim();
+        if typed.is_empty() {
+            continue;
+        }
+        
+        let mut destroyed = false;
+        for (entity, transform, bullet) in bullet_query.iter() {
+            // Same case rules as enemy words
+            let matches = match *difficulty {
+                crate::resources::Difficulty::Easy => bullet.word.eq_ignore_ascii_case(typed),
+                crate::resources::Difficulty::Hard => bullet.word == typed,
+            };
+            if matches {
+                spawn_laser_hit(&mut commands, transform.translation);
+                commands.entity(entity).despawn_recursive();
+                destroyed = true;
+            }
+        }
+        
+        if destroyed {
+            println!("Bullet destroyed!");
+            typing_buffer.clear();
+        }
+    }
+}
+
+// The player, kept apart from the enemy transforms a system also writes
+type PlayerOnly = (With<Player>, Without<Enemy>);
+
 fn collision_system(
     mut commands: Commands,
-    mut player_query: Query<(&mut Ship, &Transform), With<Player>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
-    mut next_state: ResMut<NextState<crate::resources::GameState>>,
+    player_query: Query<(&Ship, &Transform), PlayerOnly>,
+    mut enemy_query: Query<(Entity, &mut Transform, &Enemy, &mut crate::enemy::Health)>,
+    mut damage_events: EventWriter<PlayerDamageEvent>,
 ) {
-    if let Ok((mut ship, player_transform)) = player_query.get_single_mut() {
-        if !ship.invulnerability_timer.finished() {
+    if let Ok((ship, player_transform)) = player_query.get_single() {
+        if ship.is_invulnerable() {
             return;
         }
         
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, mut enemy_transform, enemy, mut health) in enemy_query.iter_mut() {
             let distance = player_transform.translation.distance(enemy_transform.translation);
             if distance < 30.0 {
-                ship.hp -= 1;
-                ship.combo = 0;
+                damage_events.send(PlayerDamageEvent {
+                    source: DamageSource::EnemyContact,
+                    amount: 1,
+                    position: enemy_transform.translation,
+                });
                 
-                spawn_explosion(&mut commands, enemy_transform.translation, Color::srgb(1.0, 0.3, 0.0), 12);
+                // Vampiric elites feed on the hit and survive it instead of crashing
+                if enemy.elite == Some(crate::elite::EliteModifier::Vampiric) {
+                    health.current += 1;
+                    health.max = health.max.max(health.current);
+                    let away = (enemy_transform.translation - player_transform.translation).normalize_or_zero();
+                    enemy_transform.translation += away * crate::elite::VAMPIRIC_KNOCKBACK;
+                    println!("Vampiric {} fed! HP: {}", enemy.archetype, health.current);
+                    continue;
+                }
                 
-                println!("Player Hit! HP: {}", ship.hp);
                 commands.entity(enemy_entity).despawn_recursive();
-                
-                if ship.hp <= 0 {
-                    println!("Game Over!");
-                    next_state.set(crate::resources::GameState::GameOver);
-                }
             }
         }
     }
 }
+
+// Anything hostile in flight that a parry can send back
+type Deflectable = AnyOf<(&'static crate::enemy::EnemyBullet, &'static crate::boss::BossParticle)>;
+
+fn parry_system(
+    mut commands: Commands,
+    mut events: EventReader<CorrectWordEvent>,
+    player_query: Query<&Ship, With<Player>>,
+    weapons: Res<WeaponRegistry>,
+    bullet_query: Query<(Entity, &Transform, Deflectable)>,
+    mut score_events: EventWriter<ScoreEvent>,
+) {
+    let Ok(ship) = player_query.get_single() else {
+        return;
+    };
+    
+    for event in events.read() {
+        let Some(parry) = &weapons.get(ship.current_weapon).parry else {
+            continue;
+        };
+        
+        for (entity, transform, (enemy_bullet, boss_particle)) in bullet_query.iter() {
+            let distance = transform.translation.distance(event.player_position);
+            if distance > parry.radius {
+                continue;
+            }
+            
+            let incoming = enemy_bullet.map(|b| b.velocity)
+                .or(boss_particle.map(|p| p.velocity))
+                .unwrap_or(Vec2::ZERO);
+            
+            // The closer the bullet got before the word landed, the harder it goes back
+            let perfect = distance <= parry.perfect_radius;
+            let (speed_multiplier, damage) = if perfect {
+                (parry.speed_multiplier * 1.5, parry.damage * 2)
+            } else {
+                (parry.speed_multiplier, parry.damage)
+            };
+            
+            commands.entity(entity)
+                .remove::<crate::enemy::EnemyBullet>()
+                .remove::<crate::boss::BossParticle>()
+                .despawn_descendants()
+                .insert(ReflectedBullet {
+                    velocity: -incoming * speed_multiplier,
+                    damage,
+                });
+            
+            if perfect {
+                score_events.send(ScoreEvent::flat(transform.translation, parry.perfect_bonus));
+                spawn_explosion(&mut commands, transform.translation, Color::srgb(1.0, 1.0, 0.3), 10);
+                println!("Perfect Parry!");
+            } else {
+                spawn_laser_hit(&mut commands, transform.translation);
+                println!("Parry!");
+            }
+        }
+    }
+}
+
+fn reflected_bullet_movement(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    mut query: Query<(Entity, &mut Transform, &ReflectedBullet)>,
+) {
+    for (entity, mut transform, bullet) in query.iter_mut() {
+        transform.translation.x += bullet.velocity.x * time.delta_secs();
+        transform.translation.y += bullet.velocity.y * time.delta_secs();
+        
+        gizmos.circle_2d(transform.translation.truncate(), 14.0, Color::srgb(0.0, 1.0, 0.8));
+        
+        let distance = transform.translation.truncate().length();
+        if distance > 800.0 {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+// Everything a reflected bullet needs from the enemy it lands on
+type ReflectTarget = (
+    Entity,
+    &'static Transform,
+    &'static mut crate::enemy::Health,
+    &'static Word,
+    &'static Enemy,
+    Option<&'static crate::enemy::EnemyShield>,
+);
+
+pub fn reflected_bullet_collision(
+    mut commands: Commands,
+    bullet_query: Query<(Entity, &Transform, &ReflectedBullet)>,
+    mut enemy_query: Query<ReflectTarget>,
+    boss_query: Query<&Transform, With<crate::boss::Boss>>,
+    mut boss_damage_events: EventWriter<crate::boss::BossDamageEvent>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut killed_events: EventWriter<crate::enemy::EnemyKilledEvent>,
+) {
+    for (bullet_entity, bullet_transform, bullet) in bullet_query.iter() {
+        if let Ok(boss_transform) = boss_query.get_single()
+            && bullet_transform.translation.distance(boss_transform.translation) < 60.0
+        {
+            // Boss damage clears lines, so however hard the bullet hits it only skips the current one
+            boss_damage_events.send(crate::boss::BossDamageEvent { amount: 1 });
+            spawn_explosion(&mut commands, bullet_transform.translation, Color::srgb(0.0, 1.0, 0.8), 12);
+            commands.entity(bullet_entity).despawn_recursive();
+            println!("Reflected bullet hit the Boss!");
+            continue;
+        }
+        
+        for (enemy_entity, enemy_transform, mut health, word, enemy, shield) in enemy_query.iter_mut() {
+            if health.current <= 0 || bullet_transform.translation.distance(enemy_transform.translation) >= 30.0 {
+                continue;
+            }
+            
+            if shield.is_some() {
+                commands.entity(enemy_entity).remove::<crate::enemy::EnemyShield>();
+                commands.entity(bullet_entity).despawn_recursive();
+                spawn_laser_hit(&mut commands, enemy_transform.translation);
+                break;
+            }
+            
+            health.current -= bullet.damage;
+            commands.entity(bullet_entity).despawn_recursive();
+            
+            if health.current <= 0 {
+                score_events.send(ScoreEvent::for_word(enemy_transform.translation, word.text.chars().count(), 1, None));
+                spawn_explosion(&mut commands, enemy_transform.translation, Color::srgb(0.0, 1.0, 0.8), 15);
+                killed_events.send(crate::enemy::EnemyKilledEvent {
+                    archetype: enemy.archetype.clone(),
+                    position: enemy_transform.translation,
+                    word: word.text.clone(),
+                    elite: enemy.elite,
+                });
+                commands.entity(enemy_entity).despawn_recursive();
+                println!("Reflected bullet destroyed an enemy!");
+            } else {
+                spawn_laser_hit(&mut commands, enemy_transform.translation);
+            }
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_weapons_parse_and_pass_the_loader_checks() {
+        let weapons: Vec<WeaponDef> = ron::from_str(DEFAULT_WEAPONS).expect("weapons.ron should parse");
+        assert_eq!(WeaponRegistry::problem(&weapons), None);
+    }
+
+    #[test]
+    fn chains_as_strong_as_the_weapon_are_rejected() {
+        let mut weapons: Vec<WeaponDef> = ron::from_str(DEFAULT_WEAPONS).unwrap();
+        let chained = weapons.iter_mut().find(|weapon| weapon.chain.is_some()).expect("a chain weapon ships by default");
+        chained.chain.as_mut().unwrap().damage = chained.damage;
+        assert!(WeaponRegistry::problem(&weapons).is_some());
+        assert!(WeaponRegistry::problem(&[]).is_some());
+    }
+}
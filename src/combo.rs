@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+use crate::player::{Player, Ship};
+
+pub struct ComboPlugin;
+
+impl Plugin for ComboPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, combo_decay.run_if(in_state(crate::resources::GameState::Running)));
+    }
+}
+
+// Seconds without a kill before the combo drops a tier
+pub const COMBO_WINDOW: f32 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ComboTier {
+    None,
+    X2,
+    X4,
+    X8,
+}
+
+impl ComboTier {
+    pub fn for_combo(combo: u32) -> Self {
+        match combo {
+            30.. => ComboTier::X8,
+            15.. => ComboTier::X4,
+            5.. => ComboTier::X2,
+            _ => ComboTier::None,
+        }
+    }
+
+    // Lowest combo that still counts as this tier
+    pub fn threshold(&self) -> u32 {
+        match self {
+            ComboTier::None => 0,
+            ComboTier::X2 => 5,
+            ComboTier::X4 => 15,
+            ComboTier::X8 => 30,
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self {
+            ComboTier::None | ComboTier::X2 => ComboTier::None,
+            ComboTier::X4 => ComboTier::X2,
+            ComboTier::X8 => ComboTier::X4,
+        }
+    }
+
+    pub fn multiplier(&self) -> u32 {
+        match self {
+            ComboTier::None => 1,
+            ComboTier::X2 => 2,
+            ComboTier::X4 => 4,
+            ComboTier::X8 => 8,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ComboTier::None => "",
+            ComboTier::X2 => "x2",
+            ComboTier::X4 => "x4",
+            ComboTier::X8 => "x8",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            ComboTier::None => Color::WHITE,
+            ComboTier::X2 => Color::srgb(0.0, 1.0, 0.5),
+            ComboTier::X4 => Color::srgb(1.0, 0.6, 0.0),
+            ComboTier::X8 => Color::srgb(1.0, 0.2, 0.8),
+        }
+    }
+
+    pub fn particle_scale(&self) -> f32 {
+        match self {
+            ComboTier::None => 1.0,
+            ComboTier::X2 => 1.3,
+            ComboTier::X4 => 1.7,
+            ComboTier::X8 => 2.2,
+        }
+    }
+
+    // High tiers arm the combo shield, which forgives one mistake
+    pub fn grants_shield(&self) -> bool {
+        *self >= ComboTier::X4
+    }
+}
+
+fn combo_decay(
+    time: Res<Time>,
+    mut player_query: Query<&mut Ship, With<Player>>,
+) {
+    let Ok(mut ship) = player_query.get_single_mut() else {
+        return;
+    };
+
+    if ship.combo == 0 {
+        return;
+    }
+
+    if ship.combo_timer.tick(time.delta()).just_finished() {
+        let tier = ship.combo_tier().previous();
+        ship.combo = tier.threshold();
+        ship.combo_shield &= tier.grants_shield();
+        println!("Combo decayed to {}", ship.combo);
+
+        if ship.combo > 0 {
+            ship.combo_timer.reset();
+        }
+    }
+}
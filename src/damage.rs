@@ -0,0 +1,161 @@
+use bevy::prelude::*;
+use crate::player::{Player, Ship};
+use crate::particles::spawn_explosion;
+
+pub struct DamagePlugin;
+
+impl Plugin for DamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlayerDamageEvent>()
+           .add_event::<PlayerDeathEvent>()
+           .add_systems(Update, (
+               apply_player_damage,
+               handle_player_death,
+           ).chain().run_if(in_state(crate::resources::GameState::Running)));
+    }
+}
+
+// Every hit gets the same grace period, whatever caused it
+const DAMAGE_IFRAMES: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageSource {
+    EnemyContact,
+    EnemyBullet,
+    BossParticle,
+    BossContact,
+    Mistake,
+}
+
+impl DamageSource {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DamageSource::EnemyContact => "Enemy",
+            DamageSource::EnemyBullet => "Enemy Bullet",
+            DamageSource::BossParticle => "Boss Particle",
+            DamageSource::BossContact => "Boss",
+            DamageSource::Mistake => "Mistake",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            DamageSource::EnemyContact => Color::srgb(1.0, 0.3, 0.0),
+            DamageSource::EnemyBullet => Color::srgb(1.0, 0.5, 0.0),
+            DamageSource::BossParticle => Color::srgb(1.0, 0.0, 0.0),
+            DamageSource::BossContact => Color::srgb(0.8, 0.0, 0.8),
+            DamageSource::Mistake => Color::srgb(1.0, 0.2, 0.2),
+        }
+    }
+}
+
+// Hazards only detect the hit and send this; shields, armor, i-frames and
+// death are all handled in one place
+#[derive(Event, Debug, Clone)]
+pub struct PlayerDamageEvent {
+    pub source: DamageSource,
+    pub amount: i32,
+    pub position: Vec3,
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct PlayerDeathEvent {
+    pub source: DamageSource,
+}
+
+// What gets through to the hull, or None when a shield charge took the hit
+fn mitigate(ship: &mut Ship, amount: i32) -> Option<i32> {
+    if ship.shield > 0 {
+        ship.shield -= 1;
+        return None;
+    }
+
+    // Armor softens hits but never turns them into nothing
+    Some((amount - ship.armor).max(1))
+}
+
+fn apply_player_damage(
+    mut commands: Commands,
+    mut events: EventReader<PlayerDamageEvent>,
+    mut player_query: Query<&mut Ship, With<Player>>,
+    mut death_events: EventWriter<PlayerDeathEvent>,
+) {
+    let Ok(mut ship) = player_query.get_single_mut() else {
+        return;
+    };
+
+    for event in events.read() {
+        if ship.hp <= 0 {
+            continue;
+        }
+
+        // Several hazards can land on the same frame; only the first one counts.
+        // Mistakes are penalties rather than hits, so they neither wait on nor grant i-frames
+        let is_hit = event.source != DamageSource::Mistake;
+        if is_hit && ship.is_invulnerable() {
+            continue;
+        }
+        spawn_explosion(&mut commands, event.position, event.source.color(), 10);
+
+        match mitigate(&mut ship, event.amount) {
+            Some(damage) => {
+                ship.hp -= damage;
+                ship.break_combo();
+                println!("Hit by {}! HP: {}", event.source.name(), ship.hp);
+            }
+            None => println!("Shield absorbed a hit from {}! Shields: {}", event.source.name(), ship.shield),
+        }
+
+        if is_hit {
+            let seconds = DAMAGE_IFRAMES + ship.invulnerability_bonus;
+            ship.grant_invulnerability(seconds);
+        }
+
+        if ship.hp <= 0 {
+            death_events.send(PlayerDeathEvent { source: event.source });
+        }
+    }
+}
+
+fn handle_player_death(
+    mut events: EventReader<PlayerDeathEvent>,
+    mut next_state: ResMut<NextState<crate::resources::GameState>>,
+) {
+    for event in events.read() {
+        println!("Game Over! Killed by {}", event.source.name());
+        next_state.set(crate::resources::GameState::GameOver);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shield_charge_absorbs_the_whole_hit() {
+        let mut ship = Ship {
+            shield: 2,
+            armor: 1,
+            ..default()
+        };
+        assert_eq!(mitigate(&mut ship, 5), None);
+        assert_eq!(ship.shield, 1);
+        assert_eq!(mitigate(&mut ship, 5), None);
+        assert_eq!(ship.shield, 0);
+        assert_eq!(mitigate(&mut ship, 5), Some(4));
+    }
+
+    #[test]
+    fn armor_reduces_damage_but_never_below_one() {
+        let mut ship = Ship {
+            armor: 2,
+            ..default()
+        };
+        assert_eq!(mitigate(&mut ship, 5), Some(3));
+        assert_eq!(mitigate(&mut ship, 2), Some(1));
+        assert_eq!(mitigate(&mut ship, 1), Some(1));
+
+        ship.armor = 0;
+        assert_eq!(mitigate(&mut ship, 3), Some(3));
+    }
+}
@@ -0,0 +1,241 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use crate::enemy::EnemyArchetype;
+use crate::player::{Player, Ship};
+use crate::waves::{WaveDef, WaveScript};
+
+pub struct DirectorPlugin;
+
+impl Plugin for DirectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Director>()
+           .add_systems(Startup, setup_director_overlay)
+           .add_systems(Update, (
+               track_director_metrics,
+               update_director,
+           ).chain().run_if(in_state(crate::resources::GameState::Running)))
+           .add_systems(Update, (
+               toggle_director_overlay,
+               update_director_overlay,
+           ));
+    }
+}
+
+// Metrics are judged over this many seconds of play
+const METRIC_WINDOW: f32 = 20.0;
+// Too few seconds of samples make WPM swing wildly
+const MIN_METRIC_SPAN: f32 = 5.0;
+const TARGET_WPM: f32 = 40.0;
+const TARGET_ACCURACY: f32 = 0.9;
+// Same distance at which enemy_movement slows time down
+const DANGER_DISTANCE: f32 = 200.0;
+// How fast intensity follows the metrics, per second
+const INTENSITY_RATE: f32 = 0.15;
+
+const MAX_INTERVAL_CHANGE: f32 = 0.35;
+const MAX_SPEED_CHANGE: f32 = 0.25;
+const MAX_WORD_SHIFT: f32 = 2.0;
+
+// Watches how the player is doing and nudges pacing within fixed bounds.
+// Only steers anything when enabled in the settings
+#[derive(Resource)]
+pub struct Director {
+    // -1.0 eases off as far as it goes, 1.0 pushes as hard as it goes
+    pub intensity: f32,
+    pub wpm: f32,
+    pub accuracy: f32,
+    pub hp_fraction: f32,
+    // Written by enemy_movement every frame
+    pub closest_enemy: f32,
+    // (time, characters) of each correct word in the window
+    words: VecDeque<(f32, usize)>,
+    mistakes: VecDeque<f32>,
+}
+
+impl Default for Director {
+    fn default() -> Self {
+        Self {
+            intensity: 0.0,
+            wpm: 0.0,
+            accuracy: 1.0,
+            hp_fraction: 1.0,
+            closest_enemy: f32::MAX,
+            words: VecDeque::new(),
+            mistakes: VecDeque::new(),
+        }
+    }
+}
+
+impl Director {
+    pub fn spawn_interval_scale(&self) -> f32 {
+        1.0 - self.intensity * MAX_INTERVAL_CHANGE
+    }
+
+    pub fn speed_scale(&self) -> f32 {
+        1.0 + self.intensity * MAX_SPEED_CHANGE
+    }
+
+    pub fn word_length_shift(&self) -> i32 {
+        (self.intensity * MAX_WORD_SHIFT).round() as i32
+    }
+
+    // Where the metrics say intensity should head
+    fn target_intensity(&self) -> f32 {
+        let speed = ((self.wpm - TARGET_WPM) / TARGET_WPM).clamp(-1.0, 1.0);
+        let precision = ((self.accuracy - TARGET_ACCURACY) / (1.0 - TARGET_ACCURACY)).clamp(-1.0, 1.0);
+        let health = self.hp_fraction * 2.0 - 1.0;
+        let danger = (1.0 - self.closest_enemy / DANGER_DISTANCE).clamp(0.0, 1.0);
+
+        ((speed + precision + health) / 3.0 - danger).clamp(-1.0, 1.0)
+    }
+}
+
+// The wave script with the director's adjustments applied on top
+#[derive(SystemParam)]
+pub struct Pacing<'w> {
+    pub script: Res<'w, WaveScript>,
+    pub director: Res<'w, Director>,
+}
+
+impl Pacing<'_> {
+    pub fn wave(&self, number: usize) -> WaveDef {
+        let mut def = self.script.wave(number);
+        def.interval *= self.director.spawn_interval_scale();
+        def
+    }
+
+    // The wave's word band, moved up or down by the director
+    pub fn shape(&self, def: &WaveDef, archetype: &EnemyArchetype) -> EnemyArchetype {
+        let mut shaped = def.shape(archetype);
+        let shift = self.director.word_length_shift();
+        let (low, high) = shaped.word_length;
+        let low = (low as i32 + shift).max(1) as usize;
+        let high = (high as i32 + shift).max(low as i32) as usize;
+        shaped.word_length = (low, high);
+        shaped
+    }
+}
+
+// Real time, since virtual time slows down whenever an enemy gets close
+fn track_director_metrics(
+    time: Res<Time<Real>>,
+    mut director: ResMut<Director>,
+    mut correct_events: EventReader<crate::combat::CorrectWordEvent>,
+    mut mistake_events: EventReader<crate::mistake::MistakeEvent>,
+    player_query: Query<&Ship, With<Player>>,
+) {
+    let now = time.elapsed_secs();
+
+    for event in correct_events.read() {
+        director.words.push_back((now, event.chars));
+    }
+    for _ in mistake_events.read() {
+        director.mistakes.push_back(now);
+    }
+
+    while director.words.front().is_some_and(|(at, _)| now - at > METRIC_WINDOW) {
+        director.words.pop_front();
+    }
+    while director.mistakes.front().is_some_and(|at| now - at > METRIC_WINDOW) {
+        director.mistakes.pop_front();
+    }
+
+    let oldest = director.words.front().map(|(at, _)| *at).unwrap_or(now);
+    let span = (now - oldest).clamp(MIN_METRIC_SPAN, METRIC_WINDOW);
+    let chars: usize = director.words.iter().map(|(_, chars)| chars).sum();
+    // The usual five characters to a word
+    director.wpm = chars as f32 / 5.0 / (span / 60.0);
+
+    let attempts = director.words.len() + director.mistakes.len();
+    director.accuracy = if attempts == 0 { 1.0 } else { director.words.len() as f32 / attempts as f32 };
+
+    if let Ok(ship) = player_query.get_single() {
+        director.hp_fraction = (ship.hp as f32 / ship.max_hp.max(1) as f32).clamp(0.0, 1.0);
+    }
+}
+
+fn update_director(
+    time: Res<Time>,
+    settings: Res<crate::resources::GameSettings>,
+    mut director: ResMut<Director>,
+) {
+    if !settings.adaptive_director {
+        director.intensity = 0.0;
+        return;
+    }
+
+    let target = director.target_intensity();
+    let step = INTENSITY_RATE * time.delta_secs();
+    director.intensity += (target - director.intensity).clamp(-step, step);
+}
+
+#[derive(Component)]
+struct DirectorOverlay;
+
+fn setup_director_overlay(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.6, 1.0, 0.6)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        DirectorOverlay,
+    ));
+}
+
+fn toggle_director_overlay(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut query: Query<&mut Visibility, With<DirectorOverlay>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F3) {
+        return;
+    }
+
+    for mut visibility in query.iter_mut() {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Visible,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+fn update_director_overlay(
+    director: Res<Director>,
+    settings: Res<crate::resources::GameSettings>,
+    mut query: Query<(&mut Text, &Visibility), With<DirectorOverlay>>,
+) {
+    for (mut text, visibility) in query.iter_mut() {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
+        let closest = if director.closest_enemy == f32::MAX {
+            "-".to_string()
+        } else {
+            format!("{:.0}", director.closest_enemy)
+        };
+
+        text.0 = format!(
+            "Director: {}  Intensity: {:+.2} (target {:+.2})\nWPM: {:.0}  Accuracy: {:.0}%  HP: {:.0}%  Closest: {}\nSpawn x{:.2}  Speed x{:.2}  Words {:+}",
+            if settings.adaptive_director { "ON" } else { "OFF" },
+            director.intensity,
+            director.target_intensity(),
+            director.wpm,
+            director.accuracy * 100.0,
+            director.hp_fraction * 100.0,
+            closest,
+            director.spawn_interval_scale(),
+            director.speed_scale(),
+            director.word_length_shift(),
+        );
+    }
+}
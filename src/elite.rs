@@ -0,0 +1,203 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::enemy::{pick_bullet_word, spawn_enemy_bullet, taken_words, Enemy, EnemyBullet, EnemyKilledEvent, EnemyText, Health, Word};
+use crate::player::Player;
+
+pub struct ElitePlugin;
+
+impl Plugin for ElitePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+            promote_elites,
+            draw_elite_auras,
+            cloak_flicker,
+            volatile_explosion,
+            elite_shield_drop,
+        ).run_if(in_state(crate::resources::GameState::Running)));
+    }
+}
+
+// Elites start showing up on this wave, and get more common after it
+const ELITE_FIRST_WAVE: usize = 3;
+const ELITE_CHANCE_PER_WAVE: f64 = 0.03;
+const ELITE_MAX_CHANCE: f64 = 0.4;
+
+const SWIFT_SPEED_MULTIPLIER: f32 = 1.5;
+const BULWARK_EXTRA_HEALTH: i32 = 2;
+const VOLATILE_BULLET_SPEED: f32 = 200.0;
+// Angles, in radians either side of the player, of the bullets a volatile elite bursts into
+const VOLATILE_SPREAD: [f32; 3] = [-0.35, 0.0, 0.35];
+const CLOAK_FLICKER_INTERVAL: f32 = 0.2;
+const CLOAK_VISIBLE_CHANCE: f64 = 0.5;
+// How far a vampiric elite bounces back after feeding
+pub const VAMPIRIC_KNOCKBACK: f32 = 150.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EliteModifier {
+    Swift,
+    Bulwark,
+    Volatile,
+    Cloaked,
+    Vampiric,
+}
+
+impl EliteModifier {
+    pub const ALL: [EliteModifier; 5] = [
+        EliteModifier::Swift,
+        EliteModifier::Bulwark,
+        EliteModifier::Volatile,
+        EliteModifier::Cloaked,
+        EliteModifier::Vampiric,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EliteModifier::Swift => "Swift",
+            EliteModifier::Bulwark => "Bulwark",
+            EliteModifier::Volatile => "Volatile",
+            EliteModifier::Cloaked => "Cloaked",
+            EliteModifier::Vampiric => "Vampiric",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            EliteModifier::Swift => Color::srgb(1.0, 1.0, 0.2),
+            EliteModifier::Bulwark => Color::srgb(0.7, 0.7, 0.7),
+            EliteModifier::Volatile => Color::srgb(1.0, 0.4, 0.0),
+            EliteModifier::Cloaked => Color::srgb(0.5, 0.3, 1.0),
+            EliteModifier::Vampiric => Color::srgb(0.8, 0.0, 0.2),
+        }
+    }
+
+    fn chance(wave: usize) -> f64 {
+        if wave < ELITE_FIRST_WAVE {
+            return 0.0;
+        }
+        ((wave - ELITE_FIRST_WAVE + 1) as f64 * ELITE_CHANCE_PER_WAVE).min(ELITE_MAX_CHANCE)
+    }
+}
+
+#[derive(Component)]
+pub struct Cloak {
+    pub timer: Timer,
+}
+
+// Rolls once for every enemy as it appears, however it was spawned
+fn promote_elites(
+    mut commands: Commands,
+    wave: Res<crate::resources::Wave>,
+    mut query: Query<(Entity, &mut Enemy, &mut Health), Added<Enemy>>,
+) {
+    let mut rng = rand::thread_rng();
+    let chance = EliteModifier::chance(wave.current);
+
+    for (entity, mut enemy, mut health) in query.iter_mut() {
+        if chance <= 0.0 || !rng.gen_bool(chance) {
+            continue;
+        }
+
+        let modifier = EliteModifier::ALL[rng.gen_range(0..EliteModifier::ALL.len())];
+        match modifier {
+            EliteModifier::Swift => enemy.speed *= SWIFT_SPEED_MULTIPLIER,
+            EliteModifier::Bulwark => {
+                health.current += BULWARK_EXTRA_HEALTH;
+                health.max += BULWARK_EXTRA_HEALTH;
+            }
+            EliteModifier::Cloaked => {
+                commands.entity(entity).insert(Cloak {
+                    timer: Timer::from_seconds(CLOAK_FLICKER_INTERVAL, TimerMode::Repeating),
+                });
+            }
+            EliteModifier::Volatile | EliteModifier::Vampiric => {}
+        }
+
+        // Worth half again as much for the trouble
+        enemy.score_value += enemy.score_value / 2;
+        enemy.elite = Some(modifier);
+        println!("{} {} appeared!", modifier.name(), enemy.archetype);
+    }
+}
+
+fn draw_elite_auras(
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    query: Query<(&Transform, &Enemy)>,
+) {
+    let pulse = 1.3 + (time.elapsed_secs() * 4.0).sin() * 0.1;
+
+    for (transform, enemy) in query.iter() {
+        if let Some(modifier) = enemy.elite {
+            gizmos.circle_2d(transform.translation.truncate(), transform.scale.x * pulse, modifier.color());
+        }
+    }
+}
+
+fn cloak_flicker(
+    time: Res<Time>,
+    mut cloak_query: Query<(&mut Cloak, &Children)>,
+    mut text_query: Query<&mut Visibility, With<EnemyText>>,
+) {
+    let mut rng = rand::thread_rng();
+
+    for (mut cloak, children) in cloak_query.iter_mut() {
+        if !cloak.timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+
+        let visible = rng.gen_bool(CLOAK_VISIBLE_CHANCE);
+        for &child in children.iter() {
+            if let Ok(mut visibility) = text_query.get_mut(child) {
+                *visibility = if visible { Visibility::Inherited } else { Visibility::Hidden };
+            }
+        }
+    }
+}
+
+fn volatile_explosion(
+    mut commands: Commands,
+    mut events: EventReader<EnemyKilledEvent>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    player_query: Query<&Transform, With<Player>>,
+    word_query: Query<&Word, With<Enemy>>,
+    bullet_query: Query<&EnemyBullet>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+    let mut taken = taken_words(word_query.iter(), bullet_query.iter());
+
+    for event in events.read() {
+        if event.elite != Some(EliteModifier::Volatile) {
+            continue;
+        }
+
+        let to_player = (player_transform.translation - event.position).truncate().normalize_or_zero();
+        for angle in VOLATILE_SPREAD {
+            let Some(word) = pick_bullet_word(&taken) else {
+                break;
+            };
+            taken.push(word.clone());
+            let velocity = Vec2::from_angle(angle).rotate(to_player) * VOLATILE_BULLET_SPEED;
+            spawn_enemy_bullet(&mut commands, &mut meshes, &mut materials, event.position, velocity, word);
+        }
+
+        crate::particles::spawn_explosion(&mut commands, event.position, EliteModifier::Volatile.color(), 20);
+        println!("Volatile {} burst into bullets!", event.archetype);
+    }
+}
+
+// Every elite leaves a shield charge behind for the trouble it caused
+fn elite_shield_drop(
+    mut commands: Commands,
+    mut events: EventReader<EnemyKilledEvent>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for event in events.read() {
+        if event.elite.is_some() {
+            crate::items::spawn_item(&mut commands, &mut meshes, &mut materials, crate::items::ItemKind::Shield, event.position);
+        }
+    }
+}
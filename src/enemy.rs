@@ -1,20 +1,33 @@
 use bevy::prelude::*;
 use rand::Rng;
+use serde::Deserialize;
 use crate::resources::{ContentManager, Wave, MapBounds};
 
 pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(SpawnTimer(Timer::from_seconds(2.0, TimerMode::Repeating)))
+        app.init_resource::<SpawnTimer>()
            .init_resource::<ContentManager>()
            .init_resource::<Wave>()
            .init_resource::<MapBounds>()
+           .init_resource::<EnemyRegistry>()
+           .add_event::<EnemyKilledEvent>()
            .add_systems(Update, (
                spawn_enemies,
                enemy_movement,
-               wave_progression,
+               support_allies,
+               enemy_shield_decay,
+               wave_progression.after(spawn_enemies),
+               // After everything that can kill, so a wave can't end before the splits land
+               split_on_death
+                   .after(crate::combat::typing_system)
+                   .after(crate::combat::reflected_bullet_collision)
+                   .after(crate::bomb::detonate_bomb)
+                   .before(wave_progression),
                text_scale_recovery,
+               phantom_label_fade,
+               sync_armor_layers,
                shooting_enemy_fire_system,
                enemy_bullet_movement,
                enemy_bullet_collision,
@@ -24,13 +37,56 @@ impl Plugin for EnemyPlugin {
 
 #[derive(Component)]
 pub struct Enemy {
+    // Name of the EnemyArchetype it was spawned from
+    pub archetype: String,
     pub speed: f32,
+    // Replaces the flat base score when this enemy is killed by a typed word
+    pub score_value: u32,
+    pub elite: Option<crate::elite::EliteModifier>,
 }
 
 #[derive(Component)]
 pub struct Word {
+    // The layer currently on the label; the only one that can be typed
     pub text: String,
     pub typed_index: usize,
+    // Armor layers still to come, in order
+    pub queue: Vec<String>,
+    // Shown on the label instead of the text, which stays the answer
+    pub scrambled: Option<String>,
+}
+
+impl Word {
+    // First entry is typed first; the rest become armor layers
+    pub fn new(mut words: Vec<String>) -> Self {
+        let text = words.remove(0);
+        Self {
+            text,
+            typed_index: 0,
+            queue: words,
+            scrambled: None,
+        }
+    }
+    
+    pub fn with_scramble(mut self, scrambled: Option<String>) -> Self {
+        self.scrambled = scrambled;
+        self
+    }
+    
+    pub fn label(&self) -> &str {
+        self.scrambled.as_deref().unwrap_or(&self.text)
+    }
+    
+    // Moves on to the next armor layer; false once the last word is showing
+    pub fn strip_layer(&mut self) -> bool {
+        if self.queue.is_empty() {
+            return false;
+        }
+        self.text = self.queue.remove(0);
+        self.typed_index = 0;
+        self.scrambled = None;
+        true
+    }
 }
 
 #[derive(Component)]
@@ -42,190 +98,700 @@ pub struct Health {
 #[derive(Component)]
 pub struct EnemyText;
 
+// Periodically heals or shields every other enemy in range
 #[derive(Component)]
-pub struct ShootingEnemy;
+pub struct Support {
+    pub effect: SupportEffect,
+    pub radius: f32,
+    pub timer: Timer,
+}
+
+const ENEMY_SHIELD_COLOR: Color = Color::srgb(0.3, 0.7, 1.0);
+
+// Absorbs the next hit, or runs out on its own
+#[derive(Component)]
+pub struct EnemyShield {
+    pub timer: Timer,
+}
+
+// Label fades out once the timer runs down, coming back only up close
+#[derive(Component)]
+pub struct Phantom {
+    pub fade_timer: Timer,
+    pub visibility: f32,
+}
+
+// One per armor layer left, drawn under the label
+#[derive(Component)]
+pub struct ArmorPip {
+    pub index: usize,
+}
+
+#[derive(Component)]
+pub struct ShootingEnemy {
+    pub bullet_speed: f32,
+}
 
 #[derive(Component)]
 pub struct EnemyBullet {
     pub velocity: Vec2,
+    // Typing this destroys the bullet
+    pub word: String,
 }
 
+#[derive(Component)]
+pub struct BulletText;
+
+const BULLET_WORDS: [&str; 12] = ["ax", "go", "up", "ox", "hi", "ok", "zap", "hit", "dot", "fly", "ice", "sky"];
+
 #[derive(Component)]
 pub struct ShootTimer {
     pub timer: Timer,
 }
 
 #[derive(Resource)]
-struct SpawnTimer(Timer);
+pub struct SpawnTimer(pub Timer);
 
-fn spawn_enemies(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut timer: ResMut<SpawnTimer>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    content_manager: ResMut<ContentManager>,
+impl Default for SpawnTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(2.0, TimerMode::Repeating))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum EnemyShape {
+    Triangle,
+    Square,
+    Circle,
+    Diamond,
+    Hexagon,
+}
+
+impl EnemyShape {
+    // Unit-sized; the spawned transform scales it up to the archetype size
+    fn mesh(&self) -> Mesh {
+        match self {
+            EnemyShape::Triangle => Triangle2d::default().into(),
+            EnemyShape::Square => Rectangle::from_size(Vec2::splat(1.0)).into(),
+            EnemyShape::Circle => Circle::new(0.5).into(),
+            EnemyShape::Diamond => Rhombus::new(1.0, 1.0).into(),
+            EnemyShape::Hexagon => RegularPolygon::new(0.5, 6).into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShootingDef {
+    pub interval: f32,
+    pub bullet_speed: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum SupportEffect {
+    // Health restored per pulse, up to the ally's max
+    Heal(i32),
+    // Seconds a granted shield lasts
+    Shield(f32),
+}
+
+impl SupportEffect {
+    fn color(&self) -> Color {
+        match self {
+            SupportEffect::Heal(_) => Color::srgb(0.2, 1.0, 0.4),
+            SupportEffect::Shield(_) => ENEMY_SHIELD_COLOR,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SupportDef {
+    pub effect: SupportEffect,
+    pub radius: f32,
+    pub interval: f32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnemyArchetype {
+    pub name: String,
+    pub shape: EnemyShape,
+    pub color: (f32, f32, f32),
+    pub size: f32,
+    pub speed: f32,
+    pub health: i32,
+    // Inclusive range of word lengths this enemy carries
+    pub word_length: (usize, usize),
+    #[serde(default)]
+    pub shooting: Option<ShootingDef>,
+    pub score_value: u32,
+    pub spawn_weight: u32,
+    // Archetype of the two children spawned when this one dies
+    #[serde(default)]
+    pub split_into: Option<String>,
+    // Extra distinct words typed one after another before the last one can kill
+    #[serde(default)]
+    pub armor: usize,
+    // Seconds before the label fades out and has to be typed from memory
+    #[serde(default)]
+    pub fade_after: Option<f32>,
+    // Label shows the word scrambled; only the unscrambled word counts
+    #[serde(default)]
+    pub anagram: bool,
+    #[serde(default)]
+    pub support: Option<SupportDef>,
+    #[serde(default)]
+    pub movement: crate::movement::MovementBehavior,
+}
+
+const DEFAULT_ARCHETYPES: &str = include_str!("../data/enemies.ron");
+
+#[derive(Resource)]
+pub struct EnemyRegistry {
+    pub archetypes: Vec<EnemyArchetype>,
+}
+
+impl Default for EnemyRegistry {
+    fn default() -> Self {
+        Self::load_from_file()
+    }
+}
+
+impl EnemyRegistry {
+    pub fn load_from_file() -> Self {
+        let path = "data/enemies.ron";
+        
+        let archetypes = match std::fs::read_to_string(path) {
+            Ok(content) => match ron::from_str::<Vec<EnemyArchetype>>(&content) {
+                Ok(archetypes) if Self::spawnable(&archetypes) => archetypes,
+                Ok(_) => {
+                    println!("Warning: {} defines no spawnable enemies, using defaults", path);
+                    Self::default_archetypes()
+                }
+                Err(e) => {
+                    println!("Warning: Failed to parse {}: {}, using defaults", path, e);
+                    Self::default_archetypes()
+                }
+            },
+            Err(_) => {
+                println!("Warning: No enemy file found, using default enemies");
+                Self::default_archetypes()
+            }
+        };
+        
+        println!("Loaded {} enemy types", archetypes.len());
+        Self { archetypes }
+    }
+    
+    // The shipped file, built in so a broken or missing copy still has something to fall back on
+    fn default_archetypes() -> Vec<EnemyArchetype> {
+        let archetypes: Vec<EnemyArchetype> = ron::from_str(DEFAULT_ARCHETYPES).expect("built-in enemies.ron should parse");
+        assert!(Self::spawnable(&archetypes), "built-in enemies.ron defines no spawnable enemies");
+        archetypes
+    }
+    
+    fn spawnable(archetypes: &[EnemyArchetype]) -> bool {
+        archetypes.iter().any(|a| a.spawn_weight > 0)
+    }
+    
+    // Weighted by spawn_weight; zero-weight entries never spawn on their own
+    pub fn pick(&self, rng: &mut impl Rng) -> &EnemyArchetype {
+        let total: u32 = self.archetypes.iter().map(|a| a.spawn_weight).sum();
+        let mut roll = rng.gen_range(0..total.max(1));
+        
+        for archetype in &self.archetypes {
+            if roll < archetype.spawn_weight {
+                return archetype;
+            }
+            roll -= archetype.spawn_weight;
+        }
+        
+        &self.archetypes[0]
+    }
+    
+    pub fn get(&self, name: &str) -> Option<&EnemyArchetype> {
+        self.archetypes.iter().find(|a| a.name == name)
+    }
+}
+
+// Sent wherever an enemy is destroyed, before its entity goes away
+#[derive(Event, Debug, Clone)]
+pub struct EnemyKilledEvent {
+    pub archetype: String,
+    pub position: Vec3,
+    pub word: String,
+    pub elite: Option<crate::elite::EliteModifier>,
+}
+
+// Label offset in pixels above the enemy, independent of its size
+const LABEL_OFFSET: f32 = 30.0;
+const PIP_OFFSET: f32 = 10.0;
+const PIP_SIZE: f32 = 6.0;
+
+pub fn spawn_enemy(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    archetype: &EnemyArchetype,
+    position: Vec2,
+    word: Word,
+) -> Entity {
+    let (r, g, b) = archetype.color;
+    let label = word.label().to_string();
+    let layers = word.queue.len();
+    
+    let mut enemy = commands.spawn((
+        Mesh2d(meshes.add(archetype.shape.mesh())),
+        MeshMaterial2d(materials.add(Color::srgb(r, g, b))),
+        Transform::from_xyz(position.x, position.y, 10.0).with_scale(Vec3::splat(archetype.size)),
+        Enemy {
+            archetype: archetype.name.clone(),
+            speed: archetype.speed,
+            score_value: archetype.score_value,
+            elite: None,
+        },
+        word,
+        Health {
+            current: archetype.health,
+            max: archetype.health,
+        },
+    ));
+    
+    if let Some(fade_after) = archetype.fade_after {
+        enemy.insert(Phantom {
+            fade_timer: Timer::from_seconds(fade_after, TimerMode::Once),
+            visibility: 1.0,
+        });
+    }
+    
+    archetype.movement.insert(&mut enemy);
+    
+    if let Some(support) = &archetype.support {
+        enemy.insert(Support {
+            effect: support.effect,
+            radius: support.radius,
+            timer: Timer::from_seconds(support.interval, TimerMode::Repeating),
+        });
+    }
+    
+    if let Some(shooting) = &archetype.shooting {
+        enemy.insert((
+            ShootingEnemy {
+                bullet_speed: shooting.bullet_speed,
+            },
+            ShootTimer {
+                timer: Timer::from_seconds(shooting.interval, TimerMode::Repeating),
+            },
+        ));
+    }
+    
+    enemy.with_children(|parent| {
+        parent.spawn((
+            Text2d::new(label),
+            TextFont {
+                font_size: 30.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Transform::from_xyz(0.0, LABEL_OFFSET / archetype.size, 1.0),
+            EnemyText,
+        ));
+        
+        let pip_mesh = meshes.add(Rectangle::from_size(Vec2::splat(PIP_SIZE / archetype.size)));
+        let pip_material = materials.add(Color::srgb(0.8, 0.9, 1.0));
+        for index in 0..layers {
+            let x = (index as f32 - (layers - 1) as f32 / 2.0) * PIP_SIZE * 2.0;
+            parent.spawn((
+                Mesh2d(pip_mesh.clone()),
+                MeshMaterial2d(pip_material.clone()),
+                Transform::from_xyz(x / archetype.size, PIP_OFFSET / archetype.size, 1.0),
+                ArmorPip { index },
+            ));
+        }
+    });
+    
+    enemy.id()
+}
+
+// What every enemy spawner needs to pick free words and put an enemy on screen
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct EnemySpawner<'w, 's> {
+    commands: Commands<'w, 's>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    materials: ResMut<'w, Assets<ColorMaterial>>,
+    content_manager: Res<'w, ContentManager>,
+    difficulty: Res<'w, crate::resources::Difficulty>,
+    settings: Res<'w, crate::resources::GameSettings>,
+    registry: Res<'w, EnemyRegistry>,
+    enemy_query: Query<'w, 's, (&'static Word, &'static Transform), With<Enemy>>,
+    bullet_query: Query<'w, 's, &'static EnemyBullet>,
+}
+
+impl EnemySpawner<'_, '_> {
+    pub fn registry(&self) -> &EnemyRegistry {
+        &self.registry
+    }
+    
+    pub fn taken(&self) -> Vec<String> {
+        taken_words(self.enemy_query.iter().map(|(word, _)| word), self.bullet_query.iter())
+    }
+    
+    // A spot on the spawn ring clear of the enemies already there, if one turns up
+    pub fn open_position(&self, rng: &mut impl Rng) -> Vec2 {
+        // Minimum distance between enemies to prevent overlap
+        const MIN_ENEMY_DISTANCE: f32 = 120.0;
+        const MAX_SPAWN_ATTEMPTS: i32 = 10;
+        const SPAWN_RADIUS: f32 = 500.0;
+        
+        let mut position = Vec2::ZERO;
+        for _ in 0..MAX_SPAWN_ATTEMPTS {
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            position = Vec2::new(SPAWN_RADIUS * angle.cos(), SPAWN_RADIUS * angle.sin());
+            
+            let too_close = self
+                .enemy_query
+                .iter()
+                .any(|(_, transform)| position.distance(transform.translation.truncate()) < MIN_ENEMY_DISTANCE);
+            if !too_close {
+                break;
+            }
+        }
+        
+        // Crowded enough that nothing fit; the last try will have to do
+        position
+    }
+    
+    pub fn fresh_word(&self, lengths: std::ops::RangeInclusive<usize>, taken: &[String]) -> Option<String> {
+        fresh_word(&self.content_manager, *self.difficulty, self.settings.keyboard_layout, lengths, taken)
+    }
+    
+    pub fn build_word(&self, first: String, archetype: &EnemyArchetype, taken: &[String], rng: &mut impl Rng) -> Word {
+        build_word(first, archetype, taken, &self.content_manager, *self.difficulty, self.settings.keyboard_layout, rng)
+    }
+    
+    pub fn spawn(&mut self, archetype: &EnemyArchetype, position: Vec2, word: Word) -> Entity {
+        spawn_enemy(&mut self.commands, &mut self.meshes, &mut self.materials, archetype, position, word)
+    }
+    
+    pub fn spawn_bullet(&mut self, position: Vec3, velocity: Vec2, word: String) {
+        spawn_enemy_bullet(&mut self.commands, &mut self.meshes, &mut self.materials, position, velocity, word);
+    }
+}
+
+// The spawn timer, kept in step with the wave's interval
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct SpawnClock<'w> {
+    time: Res<'w, Time>,
+    timer: ResMut<'w, SpawnTimer>,
+}
+
+impl SpawnClock<'_> {
+    fn tick(&mut self, interval: f32) -> bool {
+        if self.timer.0.duration().as_secs_f32() != interval {
+            self.timer.0.set_duration(std::time::Duration::from_secs_f32(interval));
+        }
+        self.timer.0.tick(self.time.delta()).just_finished()
+    }
+}
+
+const GROUP_MIN_SIZE: usize = 3;
+const GROUP_MAX_SIZE: usize = 5;
+
+pub fn spawn_enemies(
+    mut spawner: EnemySpawner,
+    mut clock: SpawnClock,
     mut wave: ResMut<Wave>,
-    difficulty: Res<crate::resources::Difficulty>,
     boss_query: Query<Entity, With<crate::boss::Boss>>,
     mut next_state: ResMut<NextState<crate::resources::GameState>>,
-    enemy_query: Query<&Transform, With<Enemy>>,
+    mut group_events: EventWriter<crate::formation::SpawnGroupEvent>,
+    pacing: crate::director::Pacing,
 ) {
-    if wave.current % 10 == 0 && wave.enemies_remaining > 0 {
-        if boss_query.is_empty() {
-            println!("Boss Wave {}! Entering warning screen...", wave.current);
+    let def = pacing.wave(wave.current);
+    
+    if !wave.started {
+        wave.started = true;
+        
+        if def.boss {
+            if boss_query.is_empty() {
+                println!("Boss Wave {}! Entering warning screen...", wave.current);
+                next_state.set(crate::resources::GameState::BossWarning);
+            }
             wave.enemies_remaining = 0;
-            next_state.set(crate::resources::GameState::BossWarning);
+            return;
+        }
+        
+        wave.enemies_remaining = def.count;
+        println!("Wave {} Started! Enemies: {}", wave.current, wave.enemies_remaining);
+    }
+    
+    if wave.enemies_remaining > 0 && clock.tick(def.interval) {
+        let mut rng = rand::thread_rng();
+        
+        // Now and then the next few enemies arrive together as a formation
+        if wave.enemies_remaining >= GROUP_MIN_SIZE && let Some(formation) = def.formation(&mut rng) {
+            let count = rng.gen_range(GROUP_MIN_SIZE..=GROUP_MAX_SIZE).min(wave.enemies_remaining);
+            group_events.send(crate::formation::SpawnGroupEvent {
+                formation,
+                archetype: def.pick(spawner.registry(), &mut rng).name.clone(),
+                count,
+            });
+            wave.enemies_remaining -= count;
+            return;
+        }
+        
+        let spawn_pos = spawner.open_position(&mut rng);
+        let archetype = pacing.shape(&def, def.pick(spawner.registry(), &mut rng));
+        let lengths = archetype.word_length.0..=archetype.word_length.1;
+        
+        let taken = spawner.taken();
+        // Wait for the screen to clear up rather than spawn a word that's already showing
+        let Some(first) = spawner.fresh_word(lengths, &taken) else {
+            return;
+        };
+        let word = spawner.build_word(first, &archetype, &taken, &mut rng);
+        
+        spawner.spawn(&archetype, spawn_pos, word);
+        wave.enemies_remaining -= 1;
+    }
+}
+
+// Everything on screen, typed or displayed, that a new word could be mistaken for
+pub fn taken_words<'a>(
+    words: impl Iterator<Item = &'a Word>,
+    bullets: impl Iterator<Item = &'a EnemyBullet>,
+) -> Vec<String> {
+    words
+        .flat_map(|w| [w.text.clone(), w.label().to_string()])
+        .chain(bullets.map(|bullet| bullet.word.clone()))
+        .collect()
+}
+
+// A word clear of everything taken; None when the paragraph has run out of them
+pub fn fresh_word(
+    content_manager: &ContentManager,
+    difficulty: crate::resources::Difficulty,
+    layout: crate::keyboard::KeyboardLayout,
+    lengths: std::ops::RangeInclusive<usize>,
+    taken: &[String],
+) -> Option<String> {
+    content_manager.get_free_word(difficulty, layout, lengths, taken)
+}
+
+// Adds the archetype's armor layers and scramble on top of an already chosen first word
+pub fn build_word(
+    first: String,
+    archetype: &EnemyArchetype,
+    taken: &[String],
+    content_manager: &ContentManager,
+    difficulty: crate::resources::Difficulty,
+    layout: crate::keyboard::KeyboardLayout,
+    rng: &mut impl Rng,
+) -> Word {
+    let lengths = archetype.word_length.0..=archetype.word_length.1;
+    
+    // One word per armor layer plus the last one, all distinct; the armor
+    // comes out thinner if there aren't enough free words
+    let mut words = vec![first];
+    for _ in 0..archetype.armor {
+        let avoid: Vec<String> = taken.iter().chain(words.iter()).cloned().collect();
+        let Some(layer) = fresh_word(content_manager, difficulty, layout, lengths.clone(), &avoid) else {
+            break;
+        };
+        words.push(layer);
+    }
+    
+    let mut word = Word::new(words);
+    if archetype.anagram {
+        let keep_first = difficulty == crate::resources::Difficulty::Easy;
+        
+        // Some words have no usable scramble ("aaa"), so try a few
+        for _ in 0..5 {
+            if let Some(scrambled) = scramble_word(&word.text, taken, keep_first, rng) {
+                return word.with_scramble(Some(scrambled));
+            }
+            let avoid: Vec<String> = taken.iter().chain(word.queue.iter()).chain([&word.text]).cloned().collect();
+            let Some(text) = fresh_word(content_manager, difficulty, layout, lengths.clone(), &avoid) else {
+                break;
+            };
+            word.text = text;
+        }
+    }
+    
+    word
+}
+
+// A shuffle of the word that differs from it and from everything taken;
+// keep_first pins the first letter as a hint
+fn scramble_word(word: &str, taken: &[String], keep_first: bool, rng: &mut impl Rng) -> Option<String> {
+    use rand::seq::SliceRandom;
+    
+    let chars: Vec<char> = word.chars().collect();
+    let fixed = if keep_first { 1 } else { 0 };
+    if chars.len() < fixed + 2 {
+        return None;
+    }
+    
+    for _ in 0..20 {
+        let mut shuffled = chars.clone();
+        shuffled[fixed..].shuffle(rng);
+        let candidate: String = shuffled.into_iter().collect();
+        
+        if !candidate.eq_ignore_ascii_case(word)
+            && !taken.iter().any(|t| t.eq_ignore_ascii_case(&candidate))
+            && !crate::bomb::is_reserved_word(&candidate)
+        {
+            return Some(candidate);
+        }
+    }
+    
+    None
+}
+
+// Halves of the parent's word when they make two distinct, unused words,
+// otherwise fresh short words that are just as unused; fewer than two when
+// the paragraph runs out
+fn split_words(parent: &str, taken: &[String], spawner: &EnemySpawner) -> Vec<String> {
+    let is_free = |w: &str| !taken.iter().any(|t| t.eq_ignore_ascii_case(w)) && !crate::bomb::is_reserved_word(w);
+    
+    let chars: Vec<char> = parent.chars().collect();
+    if chars.len() >= 4 {
+        let (left, right) = chars.split_at(chars.len() / 2);
+        let left: String = left.iter().collect();
+        let right: String = right.iter().collect();
+        if !left.eq_ignore_ascii_case(&right) && is_free(&left) && is_free(&right) {
+            return vec![left, right];
+        }
+    }
+    
+    let mut words = Vec::new();
+    for _ in 0..2 {
+        let avoid: Vec<String> = taken.iter().chain(words.iter()).cloned().collect();
+        match spawner.fresh_word(2..=4, &avoid) {
+            Some(word) => words.push(word),
+            None => break,
         }
-        return;
     }
+    words
+}
+
+fn split_on_death(
+    mut spawner: EnemySpawner,
+    mut events: EventReader<EnemyKilledEvent>,
+) {
+    let mut taken = spawner.taken();
     
-    if wave.enemies_remaining > 0 {
-        if timer.0.tick(time.delta()).just_finished() {
-            let mut rng = rand::thread_rng();
+    for event in events.read() {
+        let registry = spawner.registry();
+        let Some(child) = registry
+            .get(&event.archetype)
+            .and_then(|parent| parent.split_into.as_deref())
+            .and_then(|name| registry.get(name))
+            .cloned()
+        else {
+            continue;
+        };
+        
+        let words = split_words(&event.word, &taken, &spawner);
+        let mut rng = rand::thread_rng();
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let offset = Vec2::new(angle.cos(), angle.sin()) * 25.0;
+        
+        for (word, side) in words.into_iter().zip([1.0, -1.0]) {
+            taken.push(word.clone());
+            spawner.spawn(&child, event.position.truncate() + offset * side, Word::new(vec![word]));
+        }
+    }
+}
+
+fn support_allies(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    mut support_query: Query<(Entity, &Transform, &mut Support)>,
+    mut ally_query: Query<(Entity, &Transform, &mut Health), With<Enemy>>,
+) {
+    for (support_entity, support_transform, mut support) in support_query.iter_mut() {
+        let pulse = support.timer.tick(time.delta()).just_finished();
+        let origin = support_transform.translation.truncate();
+        
+        for (ally, ally_transform, mut health) in ally_query.iter_mut() {
+            let target = ally_transform.translation.truncate();
+            if ally == support_entity || origin.distance(target) > support.radius {
+                continue;
+            }
             
-            // Minimum distance between enemies to prevent overlap
-            const MIN_ENEMY_DISTANCE: f32 = 120.0;
-            const MAX_SPAWN_ATTEMPTS: i32 = 10;
+            // Tethers show who is being kept alive, so the player knows what to kill first
+            gizmos.line_2d(origin, target, support.effect.color().with_alpha(0.5));
             
-            // Find a valid spawn position that doesn't overlap with existing enemies
-            let mut spawn_pos = Vec2::ZERO;
-            let mut valid_position = false;
+            if !pulse {
+                continue;
+            }
             
-            for _ in 0..MAX_SPAWN_ATTEMPTS {
-                let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-                let radius = 500.0;
-                let x = radius * angle.cos();
-                let y = radius * angle.sin();
-                let test_pos = Vec2::new(x, y);
-                
-                // Check if this position is too close to any existing enemy
-                let mut too_close = false;
-                for enemy_transform in enemy_query.iter() {
-                    let enemy_pos = enemy_transform.translation.truncate();
-                    let distance = test_pos.distance(enemy_pos);
-                    
-                    if distance < MIN_ENEMY_DISTANCE {
-                        too_close = true;
-                        break;
-                    }
+            match support.effect {
+                SupportEffect::Heal(amount) => {
+                    health.current = (health.current + amount).min(health.max);
                 }
-                
-                if !too_close {
-                    spawn_pos = test_pos;
-                    valid_position = true;
-                    break;
+                SupportEffect::Shield(duration) => {
+                    commands.entity(ally).insert(EnemyShield {
+                        timer: Timer::from_seconds(duration, TimerMode::Once),
+                    });
                 }
             }
-            
-            // If no valid position found after max attempts, use the last generated position
-            // This is an edge case that should rarely happen
-            if !valid_position {
-                let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-                let radius = 500.0;
-                spawn_pos = Vec2::new(radius * angle.cos(), radius * angle.sin());
-            }
+        }
+    }
+}
 
-            let word_str = content_manager.get_word(*difficulty);
-            
-            // 30% chance to spawn shooting enemy
-            let is_shooting = rng.gen_bool(0.3);
-            
-            if is_shooting {
-                // Spawn shooting enemy - slower, shoots bullets
-                commands.spawn((
-                    Mesh2d(meshes.add(Rectangle::from_size(Vec2::splat(1.0)))),
-                    MeshMaterial2d(materials.add(Color::srgb(1.0, 0.6, 0.0))),
-                    Transform::from_xyz(spawn_pos.x, spawn_pos.y, 10.0).with_scale(Vec3::splat(20.0)),
-                    Enemy { speed: 60.0 },
-                    ShootingEnemy,
-                    ShootTimer {
-                        timer: Timer::from_seconds(2.5, TimerMode::Repeating),
-                    },
-                    Word {
-                        text: word_str.clone(),
-                        typed_index: 0,
-                    },
-                    Health {
-                        current: 2,
-                        max: 2,
-                    },
-                )).with_children(|parent| {
-                    parent.spawn((
-                        Text2d::new(word_str),
-                        TextFont {
-                            font_size: 30.0,
-                            ..default()
-                        },
-                        TextColor(Color::WHITE),
-                        Transform::from_xyz(0.0, 1.5, 1.0),
-                        EnemyText,
-                    ));
-                });
-            } else {
-                // Spawn regular enemy
-                commands.spawn((
-                    Mesh2d(meshes.add(Triangle2d::default())),
-                    MeshMaterial2d(materials.add(Color::srgb(1.0, 0.0, 0.0))),
-                    Transform::from_xyz(spawn_pos.x, spawn_pos.y, 10.0).with_scale(Vec3::splat(20.0)),
-                    Enemy { speed: 100.0 },
-                    Word {
-                        text: word_str.clone(),
-                        typed_index: 0,
-                    },
-                    Health {
-                        current: 2,
-                        max: 2,
-                    },
-                )).with_children(|parent| {
-                    parent.spawn((
-                        Text2d::new(word_str),
-                        TextFont {
-                            font_size: 30.0,
-                            ..default()
-                        },
-                        TextColor(Color::WHITE),
-                        Transform::from_xyz(0.0, 1.5, 1.0),
-                        EnemyText,
-                    ));
-                });
-            }
-            
-            wave.enemies_remaining -= 1;
+fn enemy_shield_decay(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    mut query: Query<(Entity, &Transform, &mut EnemyShield)>,
+) {
+    for (entity, transform, mut shield) in query.iter_mut() {
+        if shield.timer.tick(time.delta()).just_finished() {
+            commands.entity(entity).remove::<EnemyShield>();
+            continue;
         }
+        
+        gizmos.circle_2d(transform.translation.truncate(), transform.scale.x, ENEMY_SHIELD_COLOR);
     }
 }
 
-fn wave_progression(
+pub fn wave_progression(
     mut wave: ResMut<Wave>,
     enemy_query: Query<Entity, With<Enemy>>,
     boss_query: Query<Entity, With<crate::boss::Boss>>,
+    mut next_state: ResMut<NextState<crate::resources::GameState>>,
 ) {
-    if wave.enemies_remaining == 0 && enemy_query.is_empty() && boss_query.is_empty() {
+    // A boss wave clears enemies_remaining as it hands off to the warning screen
+    if matches!(*next_state, NextState::Pending(_)) {
+        return;
+    }
+    
+    if wave.started && wave.enemies_remaining == 0 && enemy_query.is_empty() && boss_query.is_empty() {
+        println!("Wave {} Cleared!", wave.current);
         wave.current += 1;
-        wave.enemies_remaining = wave.get_enemy_count();
-        println!("Wave {} Started! Enemies: {}", wave.current, wave.enemies_remaining);
+        wave.started = false;
+        next_state.set(crate::resources::GameState::Shop);
     }
 }
 
-fn enemy_movement(
+// Shared by every movement behavior: separation on top of the steering they
+// wrote, then the actual move
+pub fn enemy_movement(
     time: Res<Time>,
-    mut query: Query<(&mut Transform, &Enemy)>,
+    mut query: Query<(&mut Transform, &Enemy, &crate::movement::Steering)>,
     player_query: Query<&Transform, (With<crate::player::Player>, Without<Enemy>)>,
     mut time_virtual: ResMut<Time<Virtual>>,
+    speedup: Res<crate::mistake::EnemySpeedup>,
+    mut director: ResMut<crate::director::Director>,
 ) {
     if let Ok(player_transform) = player_query.get_single() {
         let player_pos = player_transform.translation;
         let mut closest_dist = f32::MAX;
 
         // Collect all enemy positions first to use for separation calculation
-        let enemy_positions: Vec<Vec3> = query.iter().map(|(t, _)| t.translation).collect();
+        let enemy_positions: Vec<Vec3> = query.iter().map(|(t, ..)| t.translation).collect();
 
-        for (mut transform, enemy) in query.iter_mut() {
-            // Seek Behavior: Move towards player
-            let to_player = player_pos - transform.translation;
-            let seek_direction = to_player.normalize_or_zero();
-            
+        for (mut transform, enemy, steering) in query.iter_mut() {
             // Separation Behavior: Move away from nearby enemies
             let mut separation_force = Vec3::ZERO;
             let separation_radius = 30.0;
@@ -242,11 +808,11 @@ fn enemy_movement(
             }
 
             // Combine forces
-            // We want to move towards player but be pushed by separation
+            // We want to follow the steering but be pushed by separation
             // Using a weighted sum
-            let final_direction = (seek_direction + separation_force * separation_weight).normalize_or_zero();
+            let final_direction = (steering.0 + separation_force * separation_weight).normalize_or_zero();
 
-            transform.translation += final_direction * enemy.speed * time.delta_secs();
+            transform.translation += final_direction * enemy.speed * speedup.current() * director.speed_scale() * time.delta_secs();
 
             // Rotate to face movement direction
             if final_direction.length_squared() > 0.0 {
@@ -260,6 +826,8 @@ fn enemy_movement(
             }
         }
         
+        director.closest_enemy = closest_dist;
+        
         if closest_dist < 200.0 {
             time_virtual.set_relative_speed(0.5);
         } else {
@@ -270,45 +838,153 @@ fn enemy_movement(
 
 fn text_scale_recovery(
     time: Res<Time>,
-    mut query: Query<&mut Transform, With<EnemyText>>,
+    mut query: Query<(&mut Transform, &Parent), With<EnemyText>>,
+    parent_query: Query<&Transform, Without<EnemyText>>,
 ) {
-    for mut transform in query.iter_mut() {
-        let base_scale = Vec3::splat(0.05);
+    for (mut transform, parent) in query.iter_mut() {
+        // Cancel out the enemy's own scale so every label renders at the same size
+        let parent_scale = parent_query.get(parent.get()).map(|t| t.scale.x).unwrap_or(20.0);
+        let base_scale = Vec3::splat(1.0 / parent_scale);
         transform.scale = transform.scale.lerp(base_scale, time.delta_secs() * 10.0);
     }
 }
 
-fn shooting_enemy_fire_system(
+// Distance at which a faded phantom label shows itself again
+const PHANTOM_REVEAL_DISTANCE: f32 = 150.0;
+const PHANTOM_FADE_SPEED: f32 = 2.0;
+const PHANTOM_REVEAL_SPEED: f32 = 6.0;
+
+fn phantom_label_fade(
+    time: Res<Time>,
+    mut phantom_query: Query<(&mut Phantom, &Transform, &Children)>,
+    player_query: Query<&Transform, (With<crate::player::Player>, Without<Phantom>)>,
+    mut text_query: Query<&mut TextColor, With<EnemyText>>,
+) {
+    let player_pos = player_query.get_single().map(|t| t.translation).ok();
+    
+    for (mut phantom, transform, children) in phantom_query.iter_mut() {
+        phantom.fade_timer.tick(time.delta());
+        
+        let near_player = player_pos
+            .is_some_and(|pos| transform.translation.distance(pos) < PHANTOM_REVEAL_DISTANCE);
+        
+        phantom.visibility = if !phantom.fade_timer.finished() || near_player {
+            (phantom.visibility + time.delta_secs() * PHANTOM_REVEAL_SPEED).min(1.0)
+        } else {
+            (phantom.visibility - time.delta_secs() * PHANTOM_FADE_SPEED).max(0.0)
+        };
+        
+        for &child in children.iter() {
+            if let Ok(mut color) = text_query.get_mut(child) {
+                color.0 = color.0.with_alpha(phantom.visibility);
+            }
+        }
+    }
+}
+
+type LabelChanged = Or<(Changed<Word>, Changed<Enemy>)>;
+
+// Keeps the label and pips in step with the armor layer being typed, and
+// puts an elite's modifier in front of its word
+fn sync_armor_layers(
     mut commands: Commands,
+    word_query: Query<(&Word, &Enemy, &Children), LabelChanged>,
+    mut text_query: Query<&mut Text2d, With<EnemyText>>,
+    pip_query: Query<&ArmorPip>,
+) {
+    for (word, enemy, children) in word_query.iter() {
+        let label = match enemy.elite {
+            Some(modifier) => format!("[{}] {}", modifier.name(), word.label()),
+            None => word.label().to_string(),
+        };
+        
+        for &child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child) && text.0 != label {
+                text.0 = label.clone();
+            }
+            if let Ok(pip) = pip_query.get(child) && pip.index >= word.queue.len() {
+                commands.entity(child).despawn_recursive();
+            }
+        }
+    }
+}
+
+// Single letters or short words, never one already on screen, so a submission
+// can only ever mean one thing; None when every one of them is in use
+pub fn pick_bullet_word(taken: &[String]) -> Option<String> {
+    let mut rng = rand::thread_rng();
+    let is_free = |w: &str| !taken.iter().any(|t| t.eq_ignore_ascii_case(w)) && !crate::bomb::is_reserved_word(w);
+    
+    for _ in 0..20 {
+        let word = if rng.gen_bool(0.6) {
+            char::from(b'a' + rng.gen_range(0..26u8)).to_string()
+        } else {
+            BULLET_WORDS[rng.gen_range(0..BULLET_WORDS.len())].to_string()
+        };
+        
+        if is_free(&word) {
+            return Some(word);
+        }
+    }
+    
+    (b'a'..=b'z')
+        .map(|b| char::from(b).to_string())
+        .chain(BULLET_WORDS.iter().map(|w| w.to_string()))
+        .find(|w| is_free(w))
+}
+
+fn shooting_enemy_fire_system(
+    mut spawner: EnemySpawner,
     time: Res<Time>,
-    mut query: Query<(&Transform, &mut ShootTimer), With<ShootingEnemy>>,
+    mut query: Query<(&Transform, &mut ShootTimer, &ShootingEnemy)>,
     player_query: Query<&Transform, With<crate::player::Player>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
     if let Ok(player_transform) = player_query.get_single() {
-        for (enemy_transform, mut shoot_timer) in query.iter_mut() {
+        let mut taken = spawner.taken();
+        
+        for (enemy_transform, mut shoot_timer, shooter) in query.iter_mut() {
             if shoot_timer.timer.tick(time.delta()).just_finished() {
                 // Calculate direction to player
                 let direction = (player_transform.translation - enemy_transform.translation).truncate().normalize();
-                let velocity = direction * 250.0;
+                let velocity = direction * shooter.bullet_speed;
+                let Some(word) = pick_bullet_word(&taken) else {
+                    continue;
+                };
+                taken.push(word.clone());
                 
-                // Spawn bullet
-                commands.spawn((
-                    Mesh2d(meshes.add(Circle::new(8.0))),
-                    MeshMaterial2d(materials.add(Color::srgb(1.0, 0.8, 0.0))),
-                    Transform::from_xyz(
-                        enemy_transform.translation.x,
-                        enemy_transform.translation.y,
-                        9.0
-                    ),
-                    EnemyBullet { velocity },
-                ));
+                spawner.spawn_bullet(enemy_transform.translation, velocity, word);
             }
         }
     }
 }
 
+pub fn spawn_enemy_bullet(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    position: Vec3,
+    velocity: Vec2,
+    word: String,
+) {
+    commands.spawn((
+        Mesh2d(meshes.add(Circle::new(8.0))),
+        MeshMaterial2d(materials.add(Color::srgb(1.0, 0.8, 0.0))),
+        Transform::from_xyz(position.x, position.y, 9.0),
+        EnemyBullet { velocity, word: word.clone() },
+    )).with_children(|parent| {
+        parent.spawn((
+            Text2d::new(word),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 1.0, 0.6)),
+            Transform::from_xyz(0.0, 18.0, 1.0),
+            BulletText,
+        ));
+    });
+}
+
 fn enemy_bullet_movement(
     mut commands: Commands,
     time: Res<Time>,
@@ -328,12 +1004,12 @@ fn enemy_bullet_movement(
 
 fn enemy_bullet_collision(
     mut commands: Commands,
-    mut player_query: Query<(&Transform, &mut crate::player::Ship), With<crate::player::Player>>,
+    player_query: Query<(&Transform, &crate::player::Ship), With<crate::player::Player>>,
     bullet_query: Query<(Entity, &Transform), With<EnemyBullet>>,
-    mut next_state: ResMut<NextState<crate::resources::GameState>>,
+    mut damage_events: EventWriter<crate::damage::PlayerDamageEvent>,
 ) {
-    if let Ok((player_transform, mut ship)) = player_query.get_single_mut() {
-        if !ship.invulnerability_timer.finished() {
+    if let Ok((player_transform, ship)) = player_query.get_single() {
+        if ship.is_invulnerable() {
             return;
         }
         
@@ -342,27 +1018,86 @@ fn enemy_bullet_collision(
             if distance < 30.0 {
                 commands.entity(bullet_entity).despawn_recursive();
                 
-                ship.hp -= 1;
-                ship.combo = 0;
-                
-                crate::particles::spawn_explosion(
-                    &mut commands,
-                    bullet_transform.translation,
-                    Color::srgb(1.0, 0.5, 0.0),
-                    8
-                );
-                
-                println!("Hit by Enemy Bullet! HP: {}", ship.hp);
-                
-                if ship.hp <= 0 {
-                    println!("Game Over!");
-                    next_state.set(crate::resources::GameState::GameOver);
-                }
-                
-                ship.invulnerability_timer = Timer::from_seconds(0.5, TimerMode::Once);
+                damage_events.send(crate::damage::PlayerDamageEvent {
+                    source: crate::damage::DamageSource::EnemyBullet,
+                    amount: 1,
+                    position: bullet_transform.translation,
+                });
                 
                 break;
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn built_in_enemies_parse_and_pass_the_loader_checks() {
+        let archetypes: Vec<EnemyArchetype> = ron::from_str(DEFAULT_ARCHETYPES).expect("enemies.ron should parse");
+        assert!(EnemyRegistry::spawnable(&archetypes));
+        
+        // Splitters have to split into something that exists
+        let registry = EnemyRegistry { archetypes };
+        for archetype in &registry.archetypes {
+            if let Some(child) = &archetype.split_into {
+                assert!(registry.get(child).is_some(), "{} splits into unknown {}", archetype.name, child);
+            }
+        }
+    }
+
+    fn sorted(word: &str) -> Vec<char> {
+        let mut chars: Vec<char> = word.chars().collect();
+        chars.sort_unstable();
+        chars
+    }
+
+    fn assert_anagram_of(anagram: &str, word: &str) {
+        assert_eq!(sorted(anagram), sorted(word), "{} is not a permutation of {}", anagram, word);
+        assert!(!anagram.eq_ignore_ascii_case(word), "{} came back unscrambled", word);
+    }
+
+    #[test]
+    fn scramble_returns_a_different_permutation() {
+        for seed in 0..50 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let anagram = scramble_word("planet", &[], false, &mut rng).expect("planet can be scrambled");
+            assert_anagram_of(&anagram, "planet");
+        }
+    }
+
+    #[test]
+    fn scramble_avoids_taken_and_reserved_words() {
+        // "nova" is a bomb command, so it is one of the arrangements to dodge
+        let taken: Vec<String> = ["onva", "vona", "avon"].iter().map(|w| w.to_string()).collect();
+        for seed in 0..50 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let anagram = scramble_word("vano", &taken, false, &mut rng).expect("vano has free arrangements");
+            assert_anagram_of(&anagram, "vano");
+            assert!(!crate::bomb::is_reserved_word(&anagram));
+            assert!(!taken.iter().any(|t| t.eq_ignore_ascii_case(&anagram)));
+        }
+
+        // Two letters with the only other arrangement taken has nothing to give
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(scramble_word("ab", &["BA".to_string()], false, &mut rng), None);
+    }
+
+    #[test]
+    fn scramble_keeps_the_first_letter_when_asked() {
+        for seed in 0..50 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let anagram = scramble_word("stream", &[], true, &mut rng).expect("stream can be scrambled");
+            assert_anagram_of(&anagram, "stream");
+            assert!(anagram.starts_with('s'));
+        }
+
+        // Nothing left to shuffle behind a fixed first letter
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(scramble_word("to", &[], true, &mut rng), None);
+    }
+}
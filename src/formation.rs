@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use crate::enemy::EnemySpawner;
+
+pub struct FormationPlugin;
+
+impl Plugin for FormationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SpawnGroupEvent>()
+           .add_systems(Update, spawn_formation
+               .after(crate::enemy::spawn_enemies)
+               .before(crate::enemy::wave_progression)
+               .run_if(in_state(crate::resources::GameState::Running)));
+    }
+}
+
+const SPAWN_RADIUS: f32 = 500.0;
+const ARC_SPREAD: f32 = std::f32::consts::FRAC_PI_2;
+const LINE_SPACING: f32 = 110.0;
+const PINCER_SPREAD: f32 = 0.3;
+const V_SPACING: f32 = 70.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Formation {
+    // Evenly spaced all the way around the player
+    Ring,
+    // Fanned out over a quarter circle
+    Arc,
+    // Side by side, sweeping in from one direction
+    Line,
+    // Split between two opposite sides
+    Pincer,
+    // Led by one enemy with the rest trailing behind on both wings
+    V,
+}
+
+impl Formation {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Formation::Ring => "Ring",
+            Formation::Arc => "Arc",
+            Formation::Line => "Line",
+            Formation::Pincer => "Pincer",
+            Formation::V => "V",
+        }
+    }
+
+    // Spawn points around the player for count enemies coming in from heading
+    pub fn positions(&self, count: usize, heading: f32) -> Vec<Vec2> {
+        let direction = |angle: f32| Vec2::new(angle.cos(), angle.sin());
+        let forward = direction(heading);
+        let side = direction(heading + std::f32::consts::FRAC_PI_2);
+        // -0.5..=0.5 across the group, 0.0 for a lone enemy
+        let spread = |i: usize, n: usize| if n > 1 { i as f32 / (n - 1) as f32 - 0.5 } else { 0.0 };
+
+        match self {
+            Formation::Ring => (0..count)
+                .map(|i| direction(heading + std::f32::consts::TAU * i as f32 / count as f32) * SPAWN_RADIUS)
+                .collect(),
+            Formation::Arc => (0..count)
+                .map(|i| direction(heading + spread(i, count) * ARC_SPREAD) * SPAWN_RADIUS)
+                .collect(),
+            Formation::Line => (0..count)
+                .map(|i| forward * SPAWN_RADIUS + side * spread(i, count) * LINE_SPACING * (count - 1) as f32)
+                .collect(),
+            Formation::Pincer => {
+                let per_side = count.div_ceil(2);
+                (0..count)
+                    .map(|i| {
+                        let flank = if i % 2 == 0 { heading } else { heading + std::f32::consts::PI };
+                        let offset = spread(i / 2, per_side) * PINCER_SPREAD * (per_side - 1) as f32;
+                        direction(flank + offset) * SPAWN_RADIUS
+                    })
+                    .collect()
+            }
+            Formation::V => (0..count)
+                .map(|i| {
+                    let rank = i.div_ceil(2) as f32;
+                    let wing = if i % 2 == 0 { -1.0 } else { 1.0 };
+                    forward * (SPAWN_RADIUS + rank * V_SPACING) + side * wing * rank * V_SPACING
+                })
+                .collect(),
+        }
+    }
+}
+
+// Spawns a whole group at once instead of one enemy per tick
+#[derive(Event, Debug, Clone)]
+pub struct SpawnGroupEvent {
+    pub formation: Formation,
+    pub archetype: String,
+    pub count: usize,
+}
+
+// Distinct words that all start with the same letter, so the group reads as one.
+// Comes up short when the paragraph runs out of free words
+fn group_words(
+    count: usize,
+    lengths: std::ops::RangeInclusive<usize>,
+    taken: &[String],
+    spawner: &EnemySpawner,
+) -> Vec<String> {
+    let Some(first) = spawner.fresh_word(lengths.clone(), taken) else {
+        return Vec::new();
+    };
+    let letter = first.chars().next().map(|c| c.to_ascii_lowercase());
+    let mut words = vec![first];
+
+    while words.len() < count {
+        let avoid: Vec<String> = taken.iter().chain(words.iter()).cloned().collect();
+        let shares_letter = (0..20)
+            .filter_map(|_| spawner.fresh_word(lengths.clone(), &avoid))
+            .find(|w| w.chars().next().map(|c| c.to_ascii_lowercase()) == letter);
+
+        match shares_letter.or_else(|| spawner.fresh_word(lengths.clone(), &avoid)) {
+            Some(word) => words.push(word),
+            None => break,
+        }
+    }
+
+    words
+}
+
+fn spawn_formation(
+    mut spawner: EnemySpawner,
+    mut events: EventReader<SpawnGroupEvent>,
+    pacing: crate::director::Pacing,
+    wave: Res<crate::resources::Wave>,
+) {
+    let mut taken = spawner.taken();
+    let mut rng = rand::thread_rng();
+    let def = pacing.wave(wave.current);
+
+    for event in events.read() {
+        let Some(archetype) = spawner.registry().get(&event.archetype).map(|archetype| pacing.shape(&def, archetype)) else {
+            println!("Warning: Unknown enemy type {} in formation", event.archetype);
+            continue;
+        };
+
+        let heading = rng.gen_range(0.0..std::f32::consts::TAU);
+        let lengths = archetype.word_length.0..=archetype.word_length.1;
+        let firsts = group_words(event.count, lengths, &taken, &spawner);
+
+        for (position, first) in event.formation.positions(event.count, heading).into_iter().zip(firsts) {
+            let word = spawner.build_word(first, &archetype, &taken, &mut rng);
+            taken.push(word.text.clone());
+            taken.push(word.label().to_string());
+            taken.extend(word.queue.iter().cloned());
+            spawner.spawn(&archetype, position, word);
+        }
+
+        println!("{} formation of {} {}s!", event.formation.name(), event.count, archetype.name);
+    }
+}
@@ -14,7 +14,17 @@ impl Plugin for GameOverPlugin {
 #[derive(Component)]
 struct GameOverUi;
 
-fn setup_game_over(mut commands: Commands) {
+fn setup_game_over(
+    mut commands: Commands,
+    finger_stats: Res<crate::keyboard::FingerStats>,
+    settings: Res<crate::resources::GameSettings>,
+    breakdown: Res<crate::score::ScoreBreakdown>,
+    player_query: Query<&crate::player::Ship, With<crate::player::Player>>,
+) {
+    use crate::keyboard::{Hand, ALL_FINGERS};
+    
+    let final_score = player_query.get_single().map(|ship| ship.score).unwrap_or(0);
+
     commands.spawn((
         Node {
             width: Val::Percent(100.0),
@@ -36,6 +46,82 @@ fn setup_game_over(mut commands: Commands) {
             TextColor(Color::srgb(1.0, 0.0, 0.0)),
         ));
         
+        parent.spawn((
+            Text::new(format!("Final Score: {}", final_score)),
+            TextFont {
+                font_size: 36.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.84, 0.0)),
+            Node {
+                margin: UiRect::top(Val::Px(20.0)),
+                ..default()
+            },
+        ));
+        
+        parent.spawn((
+            Text::new(format!(
+                "Words: {}  Length: {}  Speed: {}  Combo: {}  Modifiers: {:+}",
+                breakdown.base,
+                breakdown.length,
+                breakdown.speed,
+                breakdown.combo,
+                breakdown.modifiers,
+            )),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            Node {
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            },
+        ));
+        
+        parent.spawn((
+            Text::new(format!(
+                "{} accuracy - Left hand: {:.0}%  Right hand: {:.0}%  Home row: {:.0}%",
+                settings.keyboard_layout.name(),
+                finger_stats.hand_accuracy(Hand::Left) * 100.0,
+                finger_stats.hand_accuracy(Hand::Right) * 100.0,
+                finger_stats.home_row.accuracy() * 100.0,
+            )),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            Node {
+                margin: UiRect::top(Val::Px(20.0)),
+                ..default()
+            },
+        ));
+        
+        let finger_lines: Vec<String> = ALL_FINGERS
+            .iter()
+            .filter_map(|finger| {
+                finger_stats.fingers.get(finger).map(|tally| {
+                    format!("{}: {:.0}% ({} keys)", finger.name(), tally.accuracy() * 100.0, tally.presses)
+                })
+            })
+            .collect();
+        
+        parent.spawn((
+            Text::new(finger_lines.join("   ")),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            TextLayout::new_with_justify(JustifyText::Center),
+            Node {
+                max_width: Val::Px(900.0),
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            },
+        ));
+        
         parent.spawn((
             Text::new("Press SPACE to Restart"),
             TextFont {
@@ -52,39 +138,12 @@ fn setup_game_over(mut commands: Commands) {
 }
 
 fn game_over_input(
-    mut commands: Commands,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut next_state: ResMut<NextState<GameState>>,
-    enemy_query: Query<Entity, With<crate::enemy::Enemy>>,
-    boss_query: Query<Entity, With<crate::boss::Boss>>,
-    boss_particle_query: Query<Entity, With<crate::boss::BossParticle>>,
-    boss_health_bar_query: Query<Entity, With<crate::boss::BossHealthBar>>,
-    mut player_query: Query<&mut crate::player::Ship, With<crate::player::Player>>,
-    mut wave: ResMut<crate::resources::Wave>,
+    mut run_reset: crate::resources::RunReset,
 ) {
     if keyboard_input.just_pressed(KeyCode::Space) {
-        for entity in enemy_query.iter() {
-            commands.entity(entity).despawn_recursive();
-        }
-        
-        for entity in boss_query.iter() {
-            commands.entity(entity).despawn_recursive();
-        }
-        
-        for entity in boss_particle_query.iter() {
-            commands.entity(entity).despawn_recursive();
-        }
-        
-        for entity in boss_health_bar_query.iter() {
-            commands.entity(entity).despawn_recursive();
-        }
-        
-        if let Ok(mut ship) = player_query.get_single_mut() {
-            *ship = crate::player::Ship::default();
-        }
-        
-        *wave = crate::resources::Wave::default();
-        
+        run_reset.reset_run();
         next_state.set(GameState::Running);
     }
 }
@@ -130,5 +189,5 @@ fn reset_game_on_start(
 
     *wave = crate::resources::Wave::default();
 
-    typing_buffer.text.clear();
+    typing_buffer.clear();
 }
@@ -5,39 +5,92 @@ pub struct ItemsPlugin;
 
 impl Plugin for ItemsPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(ItemSpawnTimer(Timer::from_seconds(17.0, TimerMode::Repeating)))
+        app.init_resource::<ItemSpawnTimer>()
            .add_systems(Update, (
-            health_item_movement,
-            health_item_collection,
+            item_movement,
+            item_collection,
             spawn_periodic_items,
         ).run_if(in_state(crate::resources::GameState::Running)));
     }
 }
 
 #[derive(Resource)]
-struct ItemSpawnTimer(Timer);
+pub struct ItemSpawnTimer(Timer);
+
+impl Default for ItemSpawnTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(crate::waves::DropDef::default().interval, TimerMode::Repeating))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+    Health,
+    Bomb,
+    Shield,
+}
+
+impl ItemKind {
+    fn color(&self) -> Color {
+        match self {
+            ItemKind::Health => Color::srgb(0.0, 1.0, 0.0),
+            ItemKind::Bomb => Color::srgb(1.0, 0.5, 0.0),
+            ItemKind::Shield => Color::srgb(0.3, 0.6, 1.0),
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            ItemKind::Health => "+1",
+            ItemKind::Bomb => "B",
+            ItemKind::Shield => "S",
+        }
+    }
+
+    fn apply(&self, ship: &mut crate::player::Ship) {
+        match self {
+            ItemKind::Health => {
+                ship.hp = (ship.hp + 1).min(ship.max_hp);
+                println!("Health item collected! HP: {}", ship.hp);
+            }
+            ItemKind::Bomb => {
+                ship.bombs = (ship.bombs + 1).min(crate::bomb::MAX_BOMBS);
+                println!("Bomb item collected! Bombs: {}", ship.bombs);
+            }
+            ItemKind::Shield => {
+                ship.shield = (ship.shield + 1).min(MAX_SHIELDS);
+                println!("Shield item collected! Shields: {}", ship.shield);
+            }
+        }
+    }
+}
 
 #[derive(Component)]
-pub struct HealthItem {
+pub struct Item {
+    pub kind: ItemKind,
     pub fall_speed: f32,
 }
 
-pub fn spawn_health_item(
+pub const MAX_SHIELDS: u32 = 3;
+
+pub fn spawn_item(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ColorMaterial>>,
+    kind: ItemKind,
     position: Vec3,
 ) {
     commands.spawn((
         Mesh2d(meshes.add(Circle::new(15.0))),
-        MeshMaterial2d(materials.add(Color::srgb(0.0, 1.0, 0.0))),
+        MeshMaterial2d(materials.add(kind.color())),
         Transform::from_xyz(position.x, position.y, 9.0),
-        HealthItem {
+        Item {
+            kind,
             fall_speed: 120.0,
         },
     )).with_children(|parent| {
         parent.spawn((
-            Text2d::new("+1"),
+            Text2d::new(kind.label()),
             TextFont {
                 font_size: 25.0,
                 ..default()
@@ -48,10 +101,10 @@ pub fn spawn_health_item(
     });
 }
 
-fn health_item_movement(
+fn item_movement(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(Entity, &mut Transform, &HealthItem)>,
+    mut query: Query<(Entity, &mut Transform, &Item)>,
 ) {
     for (entity, mut transform, item) in query.iter_mut() {
         // Fall downward
@@ -64,47 +117,71 @@ fn health_item_movement(
     }
 }
 
-fn health_item_collection(
+fn item_collection(
     mut commands: Commands,
     mut player_query: Query<(&Transform, &mut crate::player::Ship), With<crate::player::Player>>,
-    item_query: Query<(Entity, &Transform), With<HealthItem>>,
+    item_query: Query<(Entity, &Transform, &Item)>,
 ) {
     if let Ok((player_transform, mut ship)) = player_query.get_single_mut() {
-        for (item_entity, item_transform) in item_query.iter() {
+        for (item_entity, item_transform, item) in item_query.iter() {
             let distance = player_transform.translation.distance(item_transform.translation);
             if distance < 40.0 {
-                // Pickup health item
-                ship.hp += 1;
+                item.kind.apply(&mut ship);
                 
                 // Visual feedback
                 crate::particles::spawn_explosion(
                     &mut commands,
                     item_transform.translation,
-                    Color::srgb(0.0, 1.0, 0.0),
+                    item.kind.color(),
                     12
                 );
                 
-                println!("Health item collected! HP: {}", ship.hp);
-                
                 commands.entity(item_entity).despawn_recursive();
             }
         }
     }
 }
 
+// The current wave's drops, sped up by the shop's supply upgrade
+#[derive(bevy::ecs::system::SystemParam)]
+struct DropSchedule<'w> {
+    upgrades: Res<'w, crate::shop::Upgrades>,
+    script: Res<'w, crate::waves::WaveScript>,
+    wave: Res<'w, crate::resources::Wave>,
+}
+
+impl DropSchedule<'_> {
+    fn current(&self) -> crate::waves::DropDef {
+        let mut drops = self.script.wave(self.wave.current).drops;
+        drops.interval *= self.upgrades.item_interval_multiplier();
+        drops
+    }
+}
+
 fn spawn_periodic_items(
     mut commands: Commands,
     time: Res<Time>,
     mut timer: ResMut<ItemSpawnTimer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    schedule: DropSchedule,
 ) {
+    let drops = schedule.current();
+    if timer.0.duration().as_secs_f32() != drops.interval {
+        timer.0.set_duration(std::time::Duration::from_secs_f32(drops.interval));
+    }
+    
     if timer.0.tick(time.delta()).just_finished() {
         let mut rng = rand::thread_rng();
         let x = rng.gen_range(-300.0..300.0);
         let y = 450.0;
         
-        spawn_health_item(&mut commands, &mut meshes, &mut materials, Vec3::new(x, y, 9.0));
-        println!("Periodic health item spawned at ({}, {})", x, y);
+        let kind = if rng.gen_bool(drops.bomb_chance.clamp(0.0, 1.0)) {
+            ItemKind::Bomb
+        } else {
+            ItemKind::Health
+        };
+        spawn_item(&mut commands, &mut meshes, &mut materials, kind, Vec3::new(x, y, 9.0));
+        println!("Periodic {:?} item spawned at ({}, {})", kind, x, y);
     }
 }
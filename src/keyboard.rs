@@ -0,0 +1,361 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+pub struct KeyboardPlugin;
+
+impl Plugin for KeyboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FingerStats>();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    Dvorak,
+    Colemak,
+    Azerty,
+    Qwertz,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hand {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Finger {
+    LeftPinky,
+    LeftRing,
+    LeftMiddle,
+    LeftIndex,
+    Thumb,
+    RightIndex,
+    RightMiddle,
+    RightRing,
+    RightPinky,
+}
+
+pub const ALL_FINGERS: [Finger; 9] = [
+    Finger::LeftPinky,
+    Finger::LeftRing,
+    Finger::LeftMiddle,
+    Finger::LeftIndex,
+    Finger::Thumb,
+    Finger::RightIndex,
+    Finger::RightMiddle,
+    Finger::RightRing,
+    Finger::RightPinky,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyRow {
+    Number,
+    Top,
+    Home,
+    Bottom,
+    Space,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyPosition {
+    pub row: KeyRow,
+    pub finger: Finger,
+}
+
+// (unshifted, shifted) characters for the number, top, home and bottom rows
+type LayoutRows = [(&'static str, &'static str); 4];
+
+const QWERTY_ROWS: LayoutRows = [
+    ("`1234567890-=", "~!@#$%^&*()_+"),
+    ("qwertyuiop[]\\", "QWERTYUIOP{}|"),
+    ("asdfghjkl;'", "ASDFGHJKL:\""),
+    ("zxcvbnm,./", "ZXCVBNM<>?"),
+];
+
+const DVORAK_ROWS: LayoutRows = [
+    ("`1234567890[]", "~!@#$%^&*(){}"),
+    ("',.pyfgcrl/=\\", "\"<>PYFGCRL?+|"),
+    ("aoeuidhtns-", "AOEUIDHTNS_"),
+    (";qjkxbmwvz", ":QJKXBMWVZ"),
+];
+
+const COLEMAK_ROWS: LayoutRows = [
+    ("`1234567890-=", "~!@#$%^&*()_+"),
+    ("qwfpgjluy;[]\\", "QWFPGJLUY:{}|"),
+    ("arstdhneio'", "ARSTDHNEIO\""),
+    ("zxcvbkm,./", "ZXCVBKM<>?"),
+];
+
+const AZERTY_ROWS: LayoutRows = [
+    ("²&é\"'(-è_çà)=", "²1234567890°+"),
+    ("azertyuiop^$", "AZERTYUIOP¨£"),
+    ("qsdfghjklmù*", "QSDFGHJKLM%µ"),
+    ("wxcvbn,;:!", "WXCVBN?./§"),
+];
+
+const QWERTZ_ROWS: LayoutRows = [
+    ("^1234567890ß´", "°!\"§$%&/()=?`"),
+    ("qwertzuiopü+", "QWERTZUIOPÜ*"),
+    ("asdfghjklöä#", "ASDFGHJKLÖÄ'"),
+    ("yxcvbnm,.-", "YXCVBNM;:_"),
+];
+
+impl KeyboardLayout {
+    pub const ALL: [KeyboardLayout; 5] = [
+        KeyboardLayout::Qwerty,
+        KeyboardLayout::Dvorak,
+        KeyboardLayout::Colemak,
+        KeyboardLayout::Azerty,
+        KeyboardLayout::Qwertz,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyboardLayout::Qwerty => "QWERTY",
+            KeyboardLayout::Dvorak => "Dvorak",
+            KeyboardLayout::Colemak => "Colemak",
+            KeyboardLayout::Azerty => "AZERTY",
+            KeyboardLayout::Qwertz => "QWERTZ",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|l| l == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Self {
+        let index = Self::ALL.iter().position(|l| l == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    fn rows(&self) -> &'static LayoutRows {
+        match self {
+            KeyboardLayout::Qwerty => &QWERTY_ROWS,
+            KeyboardLayout::Dvorak => &DVORAK_ROWS,
+            KeyboardLayout::Colemak => &COLEMAK_ROWS,
+            KeyboardLayout::Azerty => &AZERTY_ROWS,
+            KeyboardLayout::Qwertz => &QWERTZ_ROWS,
+        }
+    }
+
+    pub fn key_for(&self, c: char) -> Option<KeyPosition> {
+        if c == ' ' {
+            return Some(KeyPosition {
+                row: KeyRow::Space,
+                finger: Finger::Thumb,
+            });
+        }
+
+        let row_kinds = [KeyRow::Number, KeyRow::Top, KeyRow::Home, KeyRow::Bottom];
+        for (row, (unshifted, shifted)) in row_kinds.into_iter().zip(self.rows().iter()) {
+            for chars in [unshifted, shifted] {
+                if let Some(column) = chars.chars().position(|k| k == c) {
+                    return Some(KeyPosition {
+                        row,
+                        finger: finger_for(row, column),
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn can_type(&self, word: &str) -> bool {
+        word.chars().all(|c| self.key_for(c).is_some())
+    }
+}
+
+// Standard touch-typing assignment; the number row sits half a key left of the letters
+fn finger_for(row: KeyRow, column: usize) -> Finger {
+    let column = match row {
+        KeyRow::Number => column.saturating_sub(1),
+        KeyRow::Space => return Finger::Thumb,
+        _ => column,
+    };
+
+    match column {
+        0 => Finger::LeftPinky,
+        1 => Finger::LeftRing,
+        2 => Finger::LeftMiddle,
+        3 | 4 => Finger::LeftIndex,
+        5 | 6 => Finger::RightIndex,
+        7 => Finger::RightMiddle,
+        8 => Finger::RightRing,
+        _ => Finger::RightPinky,
+    }
+}
+
+impl Finger {
+    pub fn hand(&self) -> Option<Hand> {
+        match self {
+            Finger::LeftPinky | Finger::LeftRing | Finger::LeftMiddle | Finger::LeftIndex => Some(Hand::Left),
+            Finger::RightIndex | Finger::RightMiddle | Finger::RightRing | Finger::RightPinky => Some(Hand::Right),
+            Finger::Thumb => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Finger::LeftPinky => "L Pinky",
+            Finger::LeftRing => "L Ring",
+            Finger::LeftMiddle => "L Middle",
+            Finger::LeftIndex => "L Index",
+            Finger::Thumb => "Thumbs",
+            Finger::RightIndex => "R Index",
+            Finger::RightMiddle => "R Middle",
+            Finger::RightRing => "R Ring",
+            Finger::RightPinky => "R Pinky",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FingerTally {
+    pub presses: u32,
+    pub errors: u32,
+}
+
+impl FingerTally {
+    pub fn accuracy(&self) -> f32 {
+        if self.presses == 0 {
+            return 1.0;
+        }
+        1.0 - self.errors as f32 / self.presses as f32
+    }
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct FingerStats {
+    pub fingers: HashMap<Finger, FingerTally>,
+    pub home_row: FingerTally,
+}
+
+impl FingerStats {
+    // Compares a submitted word against the word the player was aiming for,
+    // charging each wrong or missing character to the finger that should have typed it
+    pub fn record_word(&mut self, layout: KeyboardLayout, typed: &str, expected: &str) {
+        let mut typed_chars = typed.chars();
+        for expected_char in expected.chars() {
+            let Some(key) = layout.key_for(expected_char) else {
+                typed_chars.next();
+                continue;
+            };
+
+            let correct = typed_chars.next() == Some(expected_char);
+            let tally = self.fingers.entry(key.finger).or_default();
+            tally.presses += 1;
+            if !correct {
+                tally.errors += 1;
+            }
+
+            if key.row == KeyRow::Home {
+                self.home_row.presses += 1;
+                if !correct {
+                    self.home_row.errors += 1;
+                }
+            }
+        }
+    }
+
+    pub fn hand_accuracy(&self, hand: Hand) -> f32 {
+        let (presses, errors) = self
+            .fingers
+            .iter()
+            .filter(|(finger, _)| finger.hand() == Some(hand))
+            .fold((0, 0), |(p, e), (_, tally)| (p + tally.presses, e + tally.errors));
+
+        if presses == 0 {
+            return 1.0;
+        }
+        1.0 - errors as f32 / presses as f32
+    }
+}
+
+// Picks the on-screen word sharing the longest prefix with what was typed,
+// preferring the one closest in length when several tie
+pub fn closest_target<'a>(typed: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let typed_len = typed.chars().count();
+    candidates
+        .map(|candidate| {
+            let shared = candidate
+                .chars()
+                .zip(typed.chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            let length_gap = candidate.chars().count().abs_diff(typed_len);
+            (shared, std::cmp::Reverse(length_gap), candidate)
+        })
+        .filter(|(shared, _, _)| *shared > 0)
+        .max_by_key(|(shared, length_gap, _)| (*shared, *length_gap))
+        .map(|(_, _, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_for_finds_home_row_fingers() {
+        let qwerty = KeyboardLayout::Qwerty;
+        let key = qwerty.key_for('f').unwrap();
+        assert_eq!(key.row, KeyRow::Home);
+        assert_eq!(key.finger, Finger::LeftIndex);
+        assert_eq!(qwerty.key_for('j').unwrap().finger, Finger::RightIndex);
+        assert_eq!(qwerty.key_for('a').unwrap().finger, Finger::LeftPinky);
+
+        // Same finger on Dvorak sits on a different letter
+        assert_eq!(KeyboardLayout::Dvorak.key_for('u').unwrap().finger, Finger::LeftIndex);
+    }
+
+    #[test]
+    fn key_for_handles_shift_numbers_and_space() {
+        let qwerty = KeyboardLayout::Qwerty;
+        assert_eq!(qwerty.key_for('F'), qwerty.key_for('f'));
+        assert_eq!(qwerty.key_for('1').unwrap().row, KeyRow::Number);
+        assert_eq!(qwerty.key_for('1').unwrap().finger, Finger::LeftPinky);
+        assert_eq!(
+            qwerty.key_for(' '),
+            Some(KeyPosition {
+                row: KeyRow::Space,
+                finger: Finger::Thumb,
+            })
+        );
+        assert_eq!(qwerty.key_for('é'), None);
+    }
+
+    #[test]
+    fn can_type_depends_on_the_layout() {
+        assert!(KeyboardLayout::Qwerty.can_type("Hello world"));
+        assert!(!KeyboardLayout::Qwerty.can_type("café"));
+        assert!(KeyboardLayout::Azerty.can_type("café"));
+        assert!(KeyboardLayout::Qwertz.can_type("grüße"));
+        assert!(KeyboardLayout::Qwertz.can_type(""));
+    }
+
+    #[test]
+    fn closest_target_prefers_the_longest_shared_prefix() {
+        let words = ["planet", "plasma", "orbit"];
+        assert_eq!(closest_target("plan", words.iter().copied()), Some("planet"));
+        assert_eq!(closest_target("plas", words.iter().copied()), Some("plasma"));
+        assert_eq!(closest_target("orv", words.iter().copied()), Some("orbit"));
+    }
+
+    #[test]
+    fn closest_target_breaks_ties_on_length() {
+        let words = ["star", "starship", "stare"];
+        assert_eq!(closest_target("stara", words.iter().copied()), Some("stare"));
+        assert_eq!(closest_target("starsh", words.iter().copied()), Some("starship"));
+    }
+
+    #[test]
+    fn closest_target_needs_a_shared_first_letter() {
+        let words = ["planet", "orbit"];
+        assert_eq!(closest_target("xyz", words.iter().copied()), None);
+        assert_eq!(closest_target("p", std::iter::empty()), None);
+    }
+}
@@ -12,6 +12,18 @@ mod pause;
 mod particles;
 mod boss;
 mod items;
+mod keyboard;
+mod bomb;
+mod shop;
+mod combo;
+mod score;
+mod damage;
+mod mistake;
+mod movement;
+mod formation;
+mod waves;
+mod director;
+mod elite;
 
 use player::PlayerPlugin;
 use enemy::EnemyPlugin;
@@ -25,7 +37,8 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .init_state::<GameState>()
-        .add_plugins((PlayerPlugin, EnemyPlugin, CombatPlugin, GameUiPlugin, BossPlugin, ItemsPlugin, crate::background::BackgroundPlugin, crate::game_over::GameOverPlugin, crate::menu::MenuPlugin, crate::pause::PausePlugin, crate::particles::ParticlePlugin))
+        .add_plugins((PlayerPlugin, EnemyPlugin, CombatPlugin, GameUiPlugin, BossPlugin, ItemsPlugin, crate::background::BackgroundPlugin, crate::game_over::GameOverPlugin, crate::menu::MenuPlugin, crate::pause::PausePlugin, crate::particles::ParticlePlugin, crate::keyboard::KeyboardPlugin))
+        .add_plugins((crate::bomb::BombPlugin, crate::shop::ShopPlugin, crate::combo::ComboPlugin, crate::score::ScorePlugin, crate::damage::DamagePlugin, crate::mistake::MistakePlugin, crate::movement::MovementPlugin, crate::formation::FormationPlugin, crate::waves::WavePlugin, crate::director::DirectorPlugin, crate::elite::ElitePlugin))
         .add_systems(Startup, setup_camera)
         .run();
 }
@@ -29,6 +29,26 @@ struct SettingsMenuUi;
 #[derive(Component)]
 struct DifficultyMenuUi;
 
+// Which setting a line of the settings menu shows
+#[derive(Component, Clone, Copy)]
+enum SettingText {
+    Layout,
+    ComboShield,
+    GameMode,
+    Director,
+}
+
+impl SettingText {
+    fn label(&self, settings: &GameSettings) -> String {
+        match self {
+            SettingText::Layout => format!("Layout: < {} >", settings.keyboard_layout.name()),
+            SettingText::ComboShield => format!("Combo Shield: {}", if settings.combo_shield { "ON" } else { "OFF" }),
+            SettingText::GameMode => format!("Mode: < {} >", settings.game_mode.name()),
+            SettingText::Director => format!("Adaptive Director: {}", if settings.adaptive_director { "ON" } else { "OFF" }),
+        }
+    }
+}
+
 #[derive(Component)]
 struct MenuItem {
     index: usize,
@@ -222,6 +242,10 @@ fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>) {
                 "SPACE/ENTER - Submit word",
                 "ESC - Pause game",
                 "Movement - Arrow keys",
+                "TAB - Switch weapon",
+                "Type the bomb word (shown on HUD) - Smart bomb",
+                "Type the letters on a bullet - Destroy it",
+                "F3 - Director debug overlay",
             ];
             
             for control in controls {
@@ -303,6 +327,146 @@ fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>) {
             ));
         });
         
+        parent.spawn((
+            Node {
+                width: Val::Px(600.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                margin: UiRect::all(Val::Px(10.0)),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.2, 0.7)),
+        )).with_children(|section| {
+            section.spawn((
+                Text::new("KEYBOARD"),
+                TextFont {
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.0, 0.8, 1.0)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(15.0)),
+                    ..default()
+                },
+            ));
+            
+            section.spawn((
+                Text::new(SettingText::Layout.label(&settings)),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                Node {
+                    margin: UiRect::vertical(Val::Px(5.0)),
+                    ..default()
+                },
+                SettingText::Layout,
+            ));
+            
+            section.spawn((
+                Text::new("Use Left/Right Arrow Keys to change"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+        
+        parent.spawn((
+            Node {
+                width: Val::Px(600.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                margin: UiRect::all(Val::Px(10.0)),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.2, 0.7)),
+        )).with_children(|section| {
+            section.spawn((
+                Text::new("GAMEPLAY"),
+                TextFont {
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.0, 0.8, 1.0)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(15.0)),
+                    ..default()
+                },
+            ));
+            
+            section.spawn((
+                Text::new(SettingText::GameMode.label(&settings)),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                Node {
+                    margin: UiRect::vertical(Val::Px(5.0)),
+                    ..default()
+                },
+                SettingText::GameMode,
+            ));
+            
+            section.spawn((
+                Text::new("Press M to change (Practice: no penalties, Hardcore: mistakes hurt)"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+            
+            section.spawn((
+                Text::new(SettingText::ComboShield.label(&settings)),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                Node {
+                    margin: UiRect::vertical(Val::Px(5.0)),
+                    ..default()
+                },
+                SettingText::ComboShield,
+            ));
+            
+            section.spawn((
+                Text::new("Press S to toggle (forgives one mistake at combo x4 and above)"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+            
+            section.spawn((
+                Text::new(SettingText::Director.label(&settings)),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                Node {
+                    margin: UiRect::vertical(Val::Px(5.0)),
+                    ..default()
+                },
+                SettingText::Director,
+            ));
+            
+            section.spawn((
+                Text::new("Press D to toggle (pacing adapts to how you play, F3 shows it in game)"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+        
         parent.spawn((
             Node {
                 width: Val::Px(300.0),
@@ -342,7 +506,33 @@ fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>) {
 fn settings_menu_input(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut settings: ResMut<GameSettings>,
+    mut text_query: Query<(&mut Text, &SettingText)>,
 ) {
+    if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+        settings.keyboard_layout = settings.keyboard_layout.next();
+    } else if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+        settings.keyboard_layout = settings.keyboard_layout.previous();
+    }
+    
+    if keyboard_input.just_pressed(KeyCode::KeyS) {
+        settings.combo_shield = !settings.combo_shield;
+    }
+    
+    if keyboard_input.just_pressed(KeyCode::KeyM) {
+        settings.game_mode = settings.game_mode.next();
+    }
+    
+    if keyboard_input.just_pressed(KeyCode::KeyD) {
+        settings.adaptive_director = !settings.adaptive_director;
+    }
+    
+    if settings.is_changed() {
+        for (mut text, setting) in text_query.iter_mut() {
+            text.0 = setting.label(&settings);
+        }
+    }
+    
     if keyboard_input.just_pressed(KeyCode::Escape) || keyboard_input.just_pressed(KeyCode::KeyB) {
         next_state.set(GameState::Menu);
     }
@@ -468,15 +658,12 @@ fn setup_difficulty_menu(mut commands: Commands) {
 }
 
 fn difficulty_menu_input(
-    mut commands: Commands,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut next_state: ResMut<NextState<GameState>>,
     mut difficulty: ResMut<Difficulty>,
     mut menu_selection: ResMut<MenuSelection>,
     mut menu_items: Query<(&MenuItem, &mut BackgroundColor), With<MenuItem>>,
-    enemy_query: Query<Entity, With<crate::enemy::Enemy>>,
-    mut player_query: Query<&mut crate::player::Ship, With<crate::player::Player>>,
-    mut wave: ResMut<crate::resources::Wave>,
+    mut run_reset: crate::resources::RunReset,
 ) {
     let mut selection_changed = false;
 
@@ -508,23 +695,11 @@ fn difficulty_menu_input(
 
     if keyboard_input.just_pressed(KeyCode::KeyE) {
         *difficulty = Difficulty::Easy;
-        for entity in enemy_query.iter() {
-            commands.entity(entity).despawn_recursive();
-        }
-        if let Ok(mut ship) = player_query.get_single_mut() {
-            *ship = crate::player::Ship::default();
-        }
-        *wave = crate::resources::Wave::default();
+        run_reset.reset_run();
         next_state.set(GameState::Running);
     } else if keyboard_input.just_pressed(KeyCode::KeyH) {
         *difficulty = Difficulty::Hard;
-        for entity in enemy_query.iter() {
-            commands.entity(entity).despawn_recursive();
-        }
-        if let Ok(mut ship) = player_query.get_single_mut() {
-            *ship = crate::player::Ship::default();
-        }
-        *wave = crate::resources::Wave::default();
+        run_reset.reset_run();
         next_state.set(GameState::Running);
     } else if keyboard_input.just_pressed(KeyCode::Space) || keyboard_input.just_pressed(KeyCode::Enter) {
         match menu_selection.selected_index {
@@ -532,13 +707,7 @@ fn difficulty_menu_input(
             1 => *difficulty = Difficulty::Hard,
             _ => {}
         }
-        for entity in enemy_query.iter() {
-            commands.entity(entity).despawn_recursive();
-        }
-        if let Ok(mut ship) = player_query.get_single_mut() {
-            *ship = crate::player::Ship::default();
-        }
-        *wave = crate::resources::Wave::default();
+        run_reset.reset_run();
         next_state.set(GameState::Running);
     }
 }
@@ -0,0 +1,184 @@
+use bevy::prelude::*;
+use crate::player::{Player, Ship};
+use crate::resources::{Difficulty, GameMode, GameSettings};
+
+pub struct MistakePlugin;
+
+impl Plugin for MistakePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EnemySpeedup>()
+           .add_event::<MistakeEvent>()
+           .add_systems(Update, (
+               apply_mistake_policy,
+               tick_mistake_penalties,
+           ).run_if(in_state(crate::resources::GameState::Running)));
+    }
+}
+
+// Sent by the typing systems for any submission that matched nothing
+#[derive(Event, Debug, Clone)]
+pub struct MistakeEvent {
+    pub position: Vec3,
+}
+
+// What a wrong submission costs, decided by mode and difficulty
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MistakePolicy {
+    pub reset_combo: bool,
+    pub score_penalty: u32,
+    // Seconds during which submissions are refused
+    pub lockout: f32,
+    pub enemy_speedup: f32,
+    pub speedup_duration: f32,
+    pub hp_damage: i32,
+}
+
+impl MistakePolicy {
+    pub fn for_mode(mode: GameMode, difficulty: Difficulty) -> Self {
+        match (mode, difficulty) {
+            (GameMode::Practice, _) => Self::default(),
+            (GameMode::Standard, Difficulty::Easy) => Self {
+                reset_combo: true,
+                ..default()
+            },
+            (GameMode::Standard, Difficulty::Hard) => Self {
+                reset_combo: true,
+                score_penalty: 100,
+                lockout: 0.5,
+                ..default()
+            },
+            (GameMode::Hardcore, Difficulty::Easy) => Self {
+                reset_combo: true,
+                score_penalty: 250,
+                enemy_speedup: 1.5,
+                speedup_duration: 2.0,
+                ..default()
+            },
+            (GameMode::Hardcore, Difficulty::Hard) => Self {
+                reset_combo: true,
+                score_penalty: 500,
+                lockout: 1.0,
+                enemy_speedup: 1.5,
+                speedup_duration: 3.0,
+                hp_damage: 1,
+            },
+        }
+    }
+}
+
+// Temporary multiplier on enemy speed, set by mistakes in the harsher modes
+#[derive(Resource)]
+pub struct EnemySpeedup {
+    pub multiplier: f32,
+    pub timer: Timer,
+}
+
+impl Default for EnemySpeedup {
+    fn default() -> Self {
+        Self {
+            multiplier: 1.0,
+            timer: Timer::from_seconds(0.0, TimerMode::Once),
+        }
+    }
+}
+
+impl EnemySpeedup {
+    pub fn current(&self) -> f32 {
+        if self.timer.finished() { 1.0 } else { self.multiplier }
+    }
+}
+
+fn apply_mistake_policy(
+    mut events: EventReader<MistakeEvent>,
+    settings: Res<GameSettings>,
+    difficulty: Res<Difficulty>,
+    mut player_query: Query<&mut Ship, With<Player>>,
+    mut speedup: ResMut<EnemySpeedup>,
+    mut damage_events: EventWriter<crate::damage::PlayerDamageEvent>,
+) {
+    let Ok(mut ship) = player_query.get_single_mut() else {
+        return;
+    };
+
+    let policy = MistakePolicy::for_mode(settings.game_mode, *difficulty);
+
+    for event in events.read() {
+        if policy.reset_combo && !ship.absorb_mistake(settings.combo_shield) {
+            ship.break_combo();
+        }
+
+        if policy.score_penalty > 0 {
+            ship.score = ship.score.saturating_sub(policy.score_penalty);
+            println!("Mistake penalty: -{} score", policy.score_penalty);
+        }
+
+        if policy.lockout > 0.0 {
+            ship.lockout_timer = Timer::from_seconds(policy.lockout, TimerMode::Once);
+        }
+
+        if policy.speedup_duration > 0.0 {
+            speedup.multiplier = policy.enemy_speedup;
+            speedup.timer = Timer::from_seconds(policy.speedup_duration, TimerMode::Once);
+            println!("Enemies enraged!");
+        }
+
+        if policy.hp_damage > 0 {
+            damage_events.send(crate::damage::PlayerDamageEvent {
+                source: crate::damage::DamageSource::Mistake,
+                amount: policy.hp_damage,
+                position: event.position,
+            });
+        }
+    }
+}
+
+fn tick_mistake_penalties(
+    time: Res<Time>,
+    mut player_query: Query<&mut Ship, With<Player>>,
+    mut speedup: ResMut<EnemySpeedup>,
+) {
+    if let Ok(mut ship) = player_query.get_single_mut() {
+        ship.lockout_timer.tick(time.delta());
+    }
+    speedup.timer.tick(time.delta());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn practice_is_free_on_every_difficulty() {
+        for difficulty in [Difficulty::Easy, Difficulty::Hard] {
+            assert_eq!(MistakePolicy::for_mode(GameMode::Practice, difficulty), MistakePolicy::default());
+        }
+    }
+
+    #[test]
+    fn standard_only_locks_out_on_hard() {
+        let easy = MistakePolicy::for_mode(GameMode::Standard, Difficulty::Easy);
+        assert!(easy.reset_combo);
+        assert_eq!(easy.score_penalty, 0);
+        assert_eq!(easy.lockout, 0.0);
+
+        let hard = MistakePolicy::for_mode(GameMode::Standard, Difficulty::Hard);
+        assert!(hard.reset_combo);
+        assert!(hard.score_penalty > 0);
+        assert!(hard.lockout > 0.0);
+        assert_eq!(hard.hp_damage, 0);
+    }
+
+    #[test]
+    fn hardcore_enrages_enemies_and_only_hurts_on_hard() {
+        let easy = MistakePolicy::for_mode(GameMode::Hardcore, Difficulty::Easy);
+        let hard = MistakePolicy::for_mode(GameMode::Hardcore, Difficulty::Hard);
+        for policy in [easy, hard] {
+            assert!(policy.reset_combo);
+            assert!(policy.enemy_speedup > 1.0);
+            assert!(policy.speedup_duration > 0.0);
+        }
+        assert_eq!(easy.hp_damage, 0);
+        assert!(hard.hp_damage > 0);
+        assert!(hard.score_penalty > easy.score_penalty);
+    }
+}
@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use crate::enemy::Enemy;
+use crate::player::Player;
+
+pub struct MovementPlugin;
+
+impl Plugin for MovementPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+            seek_steering,
+            orbit_steering,
+            zigzag_steering,
+            strafe_steering,
+            spiral_steering,
+            blink_steering,
+        ).before(crate::enemy::enemy_movement).run_if(in_state(crate::resources::GameState::Running)));
+    }
+}
+
+// How an archetype moves; each one becomes a component with its own system
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+pub enum MovementBehavior {
+    #[default]
+    Seek,
+    // Closes in, then circles the player at this distance
+    Orbit { radius: f32 },
+    // Weaves side to side; amplitude is the sideways weight against the approach
+    Zigzag { amplitude: f32, frequency: f32 },
+    // Keeps between the two ranges, strafing sideways while inside them
+    StrafeRetreat { min_range: f32, max_range: f32 },
+    // Curls in; turn is the sideways weight against the approach
+    Spiral { turn: f32 },
+    // Walks in and jumps forward every interval seconds
+    Blink { interval: f32, distance: f32 },
+}
+
+impl MovementBehavior {
+    pub fn insert(&self, entity: &mut EntityCommands) {
+        let mut rng = rand::thread_rng();
+        let direction = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+
+        entity.insert(Steering::default());
+        match *self {
+            MovementBehavior::Seek => {
+                entity.insert(SeekMovement);
+            }
+            MovementBehavior::Orbit { radius } => {
+                entity.insert(OrbitMovement { radius, direction });
+            }
+            MovementBehavior::Zigzag { amplitude, frequency } => {
+                entity.insert(ZigzagMovement {
+                    amplitude,
+                    frequency,
+                    phase: rng.gen_range(0.0..std::f32::consts::TAU),
+                });
+            }
+            MovementBehavior::StrafeRetreat { min_range, max_range } => {
+                entity.insert(StrafeMovement { min_range, max_range, direction });
+            }
+            MovementBehavior::Spiral { turn } => {
+                entity.insert(SpiralMovement { turn: turn * direction });
+            }
+            MovementBehavior::Blink { interval, distance } => {
+                entity.insert(BlinkMovement {
+                    timer: Timer::from_seconds(interval, TimerMode::Repeating),
+                    distance,
+                });
+            }
+        }
+    }
+}
+
+// Direction the enemy wants to go this frame; enemy_movement adds separation
+// and moves it
+#[derive(Component, Default)]
+pub struct Steering(pub Vec3);
+
+#[derive(Component)]
+pub struct SeekMovement;
+
+#[derive(Component)]
+pub struct OrbitMovement {
+    pub radius: f32,
+    // 1.0 counter-clockwise, -1.0 clockwise
+    pub direction: f32,
+}
+
+#[derive(Component)]
+pub struct ZigzagMovement {
+    pub amplitude: f32,
+    pub frequency: f32,
+    pub phase: f32,
+}
+
+#[derive(Component)]
+pub struct StrafeMovement {
+    pub min_range: f32,
+    pub max_range: f32,
+    pub direction: f32,
+}
+
+#[derive(Component)]
+pub struct SpiralMovement {
+    pub turn: f32,
+}
+
+#[derive(Component)]
+pub struct BlinkMovement {
+    pub timer: Timer,
+    pub distance: f32,
+}
+
+// Blinks never land closer to the player than this
+const BLINK_MIN_DISTANCE: f32 = 100.0;
+
+fn sideways(direction: Vec3) -> Vec3 {
+    Vec3::new(-direction.y, direction.x, 0.0)
+}
+
+type Seekers = (With<SeekMovement>, With<Enemy>);
+
+fn seek_steering(
+    mut query: Query<(&Transform, &mut Steering), Seekers>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    for (transform, mut steering) in query.iter_mut() {
+        steering.0 = (player_transform.translation - transform.translation).normalize_or_zero();
+    }
+}
+
+fn orbit_steering(
+    mut query: Query<(&Transform, &OrbitMovement, &mut Steering), With<Enemy>>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    for (transform, orbit, mut steering) in query.iter_mut() {
+        let to_player = player_transform.translation - transform.translation;
+        let inward = to_player.normalize_or_zero();
+        // Positive when too far out, negative when inside the ring
+        let correction = ((to_player.length() - orbit.radius) / orbit.radius).clamp(-1.0, 1.0);
+
+        steering.0 = inward * correction * 2.0 + sideways(inward) * orbit.direction;
+    }
+}
+
+fn zigzag_steering(
+    time: Res<Time>,
+    mut query: Query<(&Transform, &ZigzagMovement, &mut Steering), With<Enemy>>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    for (transform, zigzag, mut steering) in query.iter_mut() {
+        let inward = (player_transform.translation - transform.translation).normalize_or_zero();
+        let sway = (time.elapsed_secs() * zigzag.frequency + zigzag.phase).sin() * zigzag.amplitude;
+
+        steering.0 = inward + sideways(inward) * sway;
+    }
+}
+
+fn strafe_steering(
+    mut query: Query<(&Transform, &StrafeMovement, &mut Steering), With<Enemy>>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    for (transform, strafe, mut steering) in query.iter_mut() {
+        let to_player = player_transform.translation - transform.translation;
+        let inward = to_player.normalize_or_zero();
+        let distance = to_player.length();
+
+        steering.0 = if distance < strafe.min_range {
+            -inward
+        } else if distance > strafe.max_range {
+            inward
+        } else {
+            sideways(inward) * strafe.direction
+        };
+    }
+}
+
+fn spiral_steering(
+    mut query: Query<(&Transform, &SpiralMovement, &mut Steering), With<Enemy>>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    for (transform, spiral, mut steering) in query.iter_mut() {
+        let inward = (player_transform.translation - transform.translation).normalize_or_zero();
+        steering.0 = inward + sideways(inward) * spiral.turn;
+    }
+}
+
+fn blink_steering(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(&mut Transform, &mut BlinkMovement, &mut Steering), With<Enemy>>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    for (mut transform, mut blink, mut steering) in query.iter_mut() {
+        let to_player = player_transform.translation - transform.translation;
+        let inward = to_player.normalize_or_zero();
+        steering.0 = inward;
+
+        if blink.timer.tick(time.delta()).just_finished() {
+            let jump = blink.distance.min(to_player.length() - BLINK_MIN_DISTANCE);
+            if jump > 0.0 {
+                crate::particles::spawn_explosion(&mut commands, transform.translation, Color::srgb(0.6, 0.4, 1.0), 6);
+                transform.translation += inward * jump;
+            }
+        }
+    }
+}
@@ -28,6 +28,8 @@ pub enum ParticleType {
     Explosion, 
     Hit,       
     Error,     
+    Arc,
+    Crit,
 }
 
 pub fn spawn_blade_trail(commands: &mut Commands, start: Vec3, end: Vec3) {
@@ -65,6 +67,46 @@ pub fn spawn_blade_trail(commands: &mut Commands, start: Vec3, end: Vec3) {
     }
 }
 
+pub fn spawn_chain_arc(commands: &mut Commands, start: Vec3, end: Vec3) {
+    let mut rng = rand::thread_rng();
+    let direction = (end - start).normalize_or_zero();
+    let normal = Vec3::new(-direction.y, direction.x, 0.0);
+    
+    // Jagged bolt: jitter each joint sideways, then fill the segments with sparks
+    let segments = 8;
+    let mut joints = vec![start];
+    for i in 1..segments {
+        let t = i as f32 / segments as f32;
+        joints.push(start.lerp(end, t) + normal * rng.gen_range(-15.0..15.0));
+    }
+    joints.push(end);
+    
+    for pair in joints.windows(2) {
+        let segment_length = pair[0].distance(pair[1]);
+        let sparks = (segment_length / 6.0).max(2.0) as i32;
+        
+        for i in 0..sparks {
+            let t = i as f32 / sparks as f32;
+            
+            commands.spawn((
+                Transform::from_translation(pair[0].lerp(pair[1], t)),
+                Particle {
+                    velocity: Vec3::new(
+                        rng.gen_range(-20.0..20.0),
+                        rng.gen_range(-20.0..20.0),
+                        0.0,
+                    ),
+                    lifetime: Timer::from_seconds(rng.gen_range(0.15..0.3), TimerMode::Once),
+                    max_lifetime: 0.3,
+                    color: Color::srgb(0.7, 0.8, 1.0),
+                    size: rng.gen_range(1.5..3.0),
+                    particle_type: ParticleType::Arc,
+                },
+            ));
+        }
+    }
+}
+
 pub fn spawn_explosion(commands: &mut Commands, position: Vec3, color: Color, count: i32) {
     let mut rng = rand::thread_rng();
     
@@ -143,6 +185,34 @@ pub fn spawn_error_particles(commands: &mut Commands, position: Vec3) {
     }
 }
 
+// Evenly spaced gold ring, so a critical hit reads differently from a normal kill
+pub fn spawn_crit_burst(commands: &mut Commands, position: Vec3) {
+    let mut rng = rand::thread_rng();
+    let count = 24;
+    
+    for i in 0..count {
+        let angle = i as f32 / count as f32 * std::f32::consts::TAU;
+        let speed = rng.gen_range(280.0..340.0);
+        let velocity = Vec3::new(
+            angle.cos() * speed,
+            angle.sin() * speed,
+            0.0,
+        );
+        
+        commands.spawn((
+            Transform::from_translation(position),
+            Particle {
+                velocity,
+                lifetime: Timer::from_seconds(0.5, TimerMode::Once),
+                max_lifetime: 0.5,
+                color: Color::srgb(1.0, 0.84, 0.0),
+                size: rng.gen_range(4.0..6.0),
+                particle_type: ParticleType::Crit,
+            },
+        ));
+    }
+}
+
 fn update_particles(
     mut commands: Commands,
     time: Res<Time>,
@@ -5,7 +5,7 @@ pub struct PlayerPlugin;
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, spawn_player)
-           .add_systems(Update, (player_movement, update_invulnerability).run_if(in_state(crate::resources::GameState::Running)));
+           .add_systems(Update, (player_movement, update_invulnerability, update_energy).run_if(in_state(crate::resources::GameState::Running)));
     }
 }
 
@@ -17,11 +17,34 @@ pub struct Ship {
     pub speed: f32,
     pub rotation_speed: f32,
     pub tilt_angle: f32,
-    pub current_weapon: crate::combat::Weapon,
+    pub current_weapon: usize,
     pub score: u32,
     pub hp: i32,
+    pub max_hp: i32,
     pub combo: u32,
+    // Counts down between kills; running out drops the combo a tier
+    pub combo_timer: Timer,
+    // Armed on reaching a high combo tier, spent to forgive one mistake
+    pub combo_shield: bool,
+    // Consecutive words typed without a backspace or wrong character
+    pub perfect_streak: u32,
+    pub bombs: u32,
     pub invulnerability_timer: Timer,
+    // Each charge soaks up one hit completely; picked up from elite drops
+    pub shield: u32,
+    // Subtracted from incoming damage, which still never drops below 1
+    pub armor: i32,
+    pub energy: f32,
+    pub max_energy: f32,
+    pub energy_regen: f32,
+    pub overheat_timer: Timer,
+    // Set by the mistake policy; submissions are refused until it runs out
+    pub lockout_timer: Timer,
+    pub damage_bonus: i32,
+    // Added to the i-frames after taking a hit
+    pub invulnerability_bonus: f32,
+    // Fraction of the combo kept when it breaks
+    pub combo_retention: f32,
 }
 
 impl Default for Ship {
@@ -30,11 +53,98 @@ impl Default for Ship {
             speed: 300.0,
             rotation_speed: 10.0,
             tilt_angle: 0.0,
-            current_weapon: crate::combat::Weapon::Blade,
+            current_weapon: 0,
             score: 0,
             hp: 3,
+            max_hp: 3,
             combo: 0,
+            combo_timer: Timer::from_seconds(crate::combo::COMBO_WINDOW, TimerMode::Once),
+            combo_shield: false,
+            perfect_streak: 0,
+            bombs: 1,
             invulnerability_timer: Timer::from_seconds(0.0, TimerMode::Once),
+            shield: 0,
+            armor: 0,
+            energy: 100.0,
+            max_energy: 100.0,
+            energy_regen: 15.0,
+            overheat_timer: Timer::from_seconds(0.0, TimerMode::Once),
+            lockout_timer: Timer::from_seconds(0.0, TimerMode::Once),
+            damage_bonus: 0,
+            invulnerability_bonus: 0.0,
+            combo_retention: 0.0,
+        }
+    }
+}
+
+const OVERHEAT_DURATION: f32 = 2.0;
+
+impl Ship {
+    pub fn combo_tier(&self) -> crate::combo::ComboTier {
+        crate::combo::ComboTier::for_combo(self.combo)
+    }
+    
+    pub fn add_combo(&mut self) {
+        let previous_tier = self.combo_tier();
+        self.combo += 1;
+        self.combo_timer.reset();
+        
+        let tier = self.combo_tier();
+        if tier > previous_tier {
+            println!("Combo tier {}!", tier.name());
+            if tier.grants_shield() {
+                self.combo_shield = true;
+            }
+        }
+    }
+    
+    pub fn break_combo(&mut self) {
+        self.combo = (self.combo as f32 * self.combo_retention) as u32;
+        self.combo_shield = false;
+        self.combo_timer.reset();
+    }
+    
+    // Returns true when the shield soaked up the mistake instead of the combo
+    pub fn absorb_mistake(&mut self, shield_enabled: bool) -> bool {
+        if shield_enabled && self.combo_shield {
+            self.combo_shield = false;
+            println!("Combo shield absorbed the mistake!");
+            return true;
+        }
+        false
+    }
+    
+    // +10% per perfect word in a row, up to x2
+    pub fn perfect_multiplier(&self) -> f32 {
+        1.0 + self.perfect_streak.min(10) as f32 * 0.1
+    }
+    
+    pub fn is_invulnerable(&self) -> bool {
+        !self.invulnerability_timer.finished()
+    }
+    
+    pub fn grant_invulnerability(&mut self, seconds: f32) {
+        self.invulnerability_timer = Timer::from_seconds(seconds, TimerMode::Once);
+    }
+    
+    pub fn is_locked_out(&self) -> bool {
+        !self.lockout_timer.finished()
+    }
+    
+    pub fn is_overheated(&self) -> bool {
+        !self.overheat_timer.finished()
+    }
+    
+    pub fn drain_energy(&mut self, amount: f32) {
+        if amount <= 0.0 {
+            return;
+        }
+        
+        self.energy -= amount;
+        if self.energy <= 0.0 {
+            self.energy = 0.0;
+            self.overheat_timer = Timer::from_seconds(OVERHEAT_DURATION, TimerMode::Once);
+            println!("Overheated!");
         }
     }
 }
@@ -103,3 +213,17 @@ fn update_invulnerability(
         ship.invulnerability_timer.tick(time.delta());
     }
 }
+
+fn update_energy(
+    time: Res<Time>,
+    mut query: Query<&mut Ship, With<Player>>,
+) {
+    if let Ok(mut ship) = query.get_single_mut() {
+        ship.overheat_timer.tick(time.delta());
+        
+        // No regen while locked out, so an overheat always costs the full cooldown
+        if !ship.is_overheated() {
+            ship.energy = (ship.energy + ship.energy_regen * time.delta_secs()).min(ship.max_energy);
+        }
+    }
+}
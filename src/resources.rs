@@ -8,6 +8,7 @@ pub enum GameState {
     DifficultySelect,
     Running,
     BossWarning,
+    Shop,
     Paused,
     GameOver,
 }
@@ -19,11 +20,42 @@ pub enum Difficulty {
     Hard,
 }
 
+// Decides how harshly mistakes are punished, see crate::mistake::MistakePolicy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameMode {
+    Practice,
+    #[default]
+    Standard,
+    Hardcore,
+}
+
+impl GameMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameMode::Practice => "Practice",
+            GameMode::Standard => "Standard",
+            GameMode::Hardcore => "Hardcore",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            GameMode::Practice => GameMode::Standard,
+            GameMode::Standard => GameMode::Hardcore,
+            GameMode::Hardcore => GameMode::Practice,
+        }
+    }
+}
+
 #[derive(Resource, Debug, Clone)]
 pub struct GameSettings {
     pub master_volume: f32,
     pub sfx_volume: f32,
     pub music_volume: f32,
+    pub keyboard_layout: crate::keyboard::KeyboardLayout,
+    pub combo_shield: bool,
+    pub game_mode: GameMode,
+    pub adaptive_director: bool,
 }
 
 impl Default for GameSettings {
@@ -32,6 +64,10 @@ impl Default for GameSettings {
             master_volume: 1.0,
             sfx_volume: 1.0,
             music_volume: 1.0,
+            keyboard_layout: crate::keyboard::KeyboardLayout::default(),
+            combo_shield: true,
+            game_mode: GameMode::default(),
+            adaptive_director: false,
         }
     }
 }
@@ -83,27 +119,72 @@ pub struct Wave {
     pub current: usize,
     pub enemies_remaining: usize,
     pub timer: Timer,
+    // Set once the wave script has filled in enemies_remaining for this wave
+    pub started: bool,
 }
 
 impl Default for Wave {
     fn default() -> Self {
         Self {
             current: 1,
-            enemies_remaining: 5,
+            enemies_remaining: 0,
             timer: Timer::from_seconds(30.0, TimerMode::Repeating),
+            started: false,
         }
     }
 }
 
-impl Wave {
-    pub fn get_enemy_count(&self) -> usize {
-        let primes = [5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
-        if self.current <= primes.len() {
-            primes[self.current - 1]
-        } else {
-            primes.last().unwrap() + (self.current - primes.len()) * 2
+// Everything left over from the last run, for restart sites to clear in one go
+type RunEntities = Or<(
+    With<crate::enemy::Enemy>,
+    With<crate::enemy::EnemyBullet>,
+    With<crate::combat::ReflectedBullet>,
+    With<crate::boss::Boss>,
+    With<crate::boss::BossParticle>,
+    With<crate::boss::BossHealthBar>,
+    With<crate::items::Item>,
+    With<crate::bomb::Shockwave>,
+    With<crate::score::ScorePopup>,
+    With<crate::particles::Particle>,
+)>;
+
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct RunReset<'w, 's> {
+    commands: Commands<'w, 's>,
+    leftovers: Query<'w, 's, Entity, RunEntities>,
+    player_query: Query<'w, 's, &'static mut crate::player::Ship, With<crate::player::Player>>,
+    wave: ResMut<'w, Wave>,
+    finger_stats: ResMut<'w, crate::keyboard::FingerStats>,
+    upgrades: ResMut<'w, crate::shop::Upgrades>,
+    score_breakdown: ResMut<'w, crate::score::ScoreBreakdown>,
+    director: ResMut<'w, crate::director::Director>,
+    speedup: ResMut<'w, crate::mistake::EnemySpeedup>,
+    bomb_command: ResMut<'w, crate::bomb::BombCommand>,
+    spawn_timer: ResMut<'w, crate::enemy::SpawnTimer>,
+    item_timer: ResMut<'w, crate::items::ItemSpawnTimer>,
+    typing_buffer: ResMut<'w, crate::ui::TypingBuffer>,
+}
+
+impl RunReset<'_, '_> {
+    pub fn reset_run(&mut self) {
+        for entity in self.leftovers.iter() {
+            self.commands.entity(entity).despawn_recursive();
+        }
+        
+        if let Ok(mut ship) = self.player_query.get_single_mut() {
+            *ship = crate::player::Ship::default();
         }
-        // 1
+        
+        *self.wave = Wave::default();
+        *self.finger_stats = crate::keyboard::FingerStats::default();
+        *self.upgrades = crate::shop::Upgrades::default();
+        *self.score_breakdown = crate::score::ScoreBreakdown::default();
+        *self.director = crate::director::Director::default();
+        *self.speedup = crate::mistake::EnemySpeedup::default();
+        *self.bomb_command = crate::bomb::BombCommand::default();
+        *self.spawn_timer = crate::enemy::SpawnTimer::default();
+        *self.item_timer = crate::items::ItemSpawnTimer::default();
+        *self.typing_buffer = crate::ui::TypingBuffer::default();
     }
 }
 
@@ -185,22 +266,54 @@ impl ContentManager {
         }
     }
     
-    pub fn get_word(&self, difficulty: Difficulty) -> String {
+    // A word from the current paragraph that isn't one of taken in any case;
+    // None once the paragraph has nothing else left to offer
+    pub fn get_free_word(
+        &self,
+        difficulty: Difficulty,
+        layout: crate::keyboard::KeyboardLayout,
+        lengths: std::ops::RangeInclusive<usize>,
+        taken: &[String],
+    ) -> Option<String> {
         use rand::Rng;
         let mut rng = rand::thread_rng();
         
-        if self.paragraphs.is_empty() {
-            return "error".to_string();
-        }
+        let current_paragraph = self.paragraphs.get(self.current_index)?;
         
-        let current_paragraph = &self.paragraphs[self.current_index];
-        if current_paragraph.unique_words.is_empty() {
-            return "empty".to_string();
+        // Bomb command words are never handed out, so typing one can't hit an enemy
+        let candidates: Vec<&String> = current_paragraph
+            .unique_words
+            .iter()
+            .filter(|w| !crate::bomb::is_reserved_word(w))
+            .filter(|w| !taken.iter().any(|t| t.eq_ignore_ascii_case(w)))
+            .collect();
+        if candidates.is_empty() {
+            return None;
         }
         
-        let base_word = &current_paragraph.unique_words[rng.gen_range(0..current_paragraph.unique_words.len())];
+        // Stick to the requested length band when the paragraph has any words in it
+        let sized: Vec<&String> = candidates
+            .iter()
+            .copied()
+            .filter(|w| lengths.contains(&w.chars().count()))
+            .collect();
+        let candidates = if sized.is_empty() { candidates } else { sized };
+        
+        // Only offer words the active layout can actually type; content the layout
+        // can't cover at all (e.g. IME languages) falls back to the full list
+        let typable: Vec<&String> = candidates
+            .iter()
+            .copied()
+            .filter(|w| layout.can_type(w))
+            .collect();
         
-        match difficulty {
+        let base_word = if typable.is_empty() {
+            candidates[rng.gen_range(0..candidates.len())]
+        } else {
+            typable[rng.gen_range(0..typable.len())]
+        };
+        
+        Some(match difficulty {
             Difficulty::Easy => base_word.to_lowercase(),
             Difficulty::Hard => {
                 base_word.chars().map(|c| {
@@ -211,7 +324,7 @@ impl ContentManager {
                     }
                 }).collect()
             }
-        }
+        })
     }
     
     pub fn get_current_lines(&self) -> Vec<String> {
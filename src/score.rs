@@ -0,0 +1,173 @@
+use bevy::prelude::*;
+use crate::player::{Player, Ship};
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScoreBreakdown>()
+           .add_event::<ScoreEvent>()
+           .add_systems(Update, (
+               apply_score_events,
+               spawn_score_popups,
+               update_score_popups,
+           ).run_if(in_state(crate::resources::GameState::Running)));
+    }
+}
+
+pub const BASE_SCORE: u32 = 100;
+pub const LENGTH_BONUS_PER_CHAR: u32 = 100;
+// Typing slower than this per character earns no speed bonus
+const SPEED_BONUS_SECS_PER_CHAR: f32 = 0.5;
+
+// Every point earned goes through one of these, so the game-over screen can
+// show where the points came from
+#[derive(Event, Debug, Clone)]
+pub struct ScoreEvent {
+    pub position: Vec3,
+    pub base: u32,
+    pub length_bonus: u32,
+    pub speed_bonus: u32,
+    pub combo_multiplier: u32,
+    // Weapon, critical and streak multipliers folded together
+    pub modifier: f32,
+}
+
+impl ScoreEvent {
+    pub fn flat(position: Vec3, amount: u32) -> Self {
+        Self {
+            position,
+            base: amount,
+            length_bonus: 0,
+            speed_bonus: 0,
+            combo_multiplier: 1,
+            modifier: 1.0,
+        }
+    }
+
+    // A typed word or line; typing_secs is how long it took from first key to Enter
+    pub fn for_word(position: Vec3, len: usize, combo_multiplier: u32, typing_secs: Option<f32>) -> Self {
+        let len = len.max(1) as u32;
+        let speed_bonus = typing_secs
+            .map(|secs| {
+                let pace = 1.0 - (secs / len as f32) / SPEED_BONUS_SECS_PER_CHAR;
+                (pace.max(0.0) * (len * LENGTH_BONUS_PER_CHAR) as f32) as u32
+            })
+            .unwrap_or(0);
+
+        Self {
+            position,
+            base: BASE_SCORE,
+            length_bonus: (len - 1) * LENGTH_BONUS_PER_CHAR,
+            speed_bonus,
+            combo_multiplier,
+            modifier: 1.0,
+        }
+    }
+
+    pub fn with_base(mut self, base: u32) -> Self {
+        self.base = base;
+        self
+    }
+
+    pub fn with_modifier(mut self, modifier: f32) -> Self {
+        self.modifier *= modifier;
+        self
+    }
+
+    fn raw(&self) -> u32 {
+        self.base + self.length_bonus + self.speed_bonus
+    }
+
+    pub fn total(&self) -> u32 {
+        ((self.raw() * self.combo_multiplier) as f32 * self.modifier) as u32
+    }
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct ScoreBreakdown {
+    pub base: u64,
+    pub length: u64,
+    pub speed: u64,
+    pub combo: u64,
+    // Can go negative when a weapon scores below 1x
+    pub modifiers: i64,
+}
+
+#[derive(Component)]
+pub struct ScorePopup {
+    timer: Timer,
+}
+
+fn apply_score_events(
+    mut events: EventReader<ScoreEvent>,
+    mut breakdown: ResMut<ScoreBreakdown>,
+    mut player_query: Query<&mut Ship, With<Player>>,
+) {
+    let Ok(mut ship) = player_query.get_single_mut() else {
+        return;
+    };
+
+    for event in events.read() {
+        let raw = event.raw();
+        let with_combo = raw * event.combo_multiplier;
+        let total = event.total();
+
+        breakdown.base += event.base as u64;
+        breakdown.length += event.length_bonus as u64;
+        breakdown.speed += event.speed_bonus as u64;
+        breakdown.combo += (with_combo - raw) as u64;
+        breakdown.modifiers += total as i64 - with_combo as i64;
+
+        ship.score += total;
+    }
+}
+
+fn spawn_score_popups(
+    mut commands: Commands,
+    mut events: EventReader<ScoreEvent>,
+) {
+    for event in events.read() {
+        let total = event.total();
+        if total == 0 {
+            continue;
+        }
+
+        let color = if event.modifier > 1.0 {
+            Color::srgb(1.0, 0.84, 0.0)
+        } else {
+            Color::WHITE
+        };
+
+        commands.spawn((
+            Text2d::new(format!("+{}", total)),
+            TextFont {
+                font_size: 24.0,
+                ..default()
+            },
+            TextColor(color),
+            Transform::from_translation(event.position + Vec3::new(0.0, 40.0, 20.0)),
+            ScorePopup {
+                timer: Timer::from_seconds(0.9, TimerMode::Once),
+            },
+        ));
+    }
+}
+
+fn update_score_popups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform, &mut TextColor, &mut ScorePopup)>,
+) {
+    for (entity, mut transform, mut color, mut popup) in query.iter_mut() {
+        popup.timer.tick(time.delta());
+
+        if popup.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation.y += 60.0 * time.delta_secs();
+        color.0 = color.0.with_alpha(1.0 - popup.timer.fraction());
+    }
+}
@@ -0,0 +1,291 @@
+use bevy::prelude::*;
+use crate::resources::{GameState, MenuSelection};
+use crate::player::{Player, Ship};
+
+pub struct ShopPlugin;
+
+impl Plugin for ShopPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Upgrades>()
+           .add_systems(OnEnter(GameState::Shop), (reset_shop_selection, setup_shop).chain())
+           .add_systems(Update, (shop_input, update_shop_text).chain().run_if(in_state(GameState::Shop)))
+           .add_systems(OnExit(GameState::Shop), cleanup_shop);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Upgrade {
+    MaxHp,
+    WeaponDamage,
+    MoveSpeed,
+    Invulnerability,
+    ComboRetention,
+    ItemRate,
+}
+
+impl Upgrade {
+    pub const ALL: [Upgrade; 6] = [
+        Upgrade::MaxHp,
+        Upgrade::WeaponDamage,
+        Upgrade::MoveSpeed,
+        Upgrade::Invulnerability,
+        Upgrade::ComboRetention,
+        Upgrade::ItemRate,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Upgrade::MaxHp => "Hull Plating",
+            Upgrade::WeaponDamage => "Weapon Damage",
+            Upgrade::MoveSpeed => "Thrusters",
+            Upgrade::Invulnerability => "Shield Capacitor",
+            Upgrade::ComboRetention => "Combo Memory",
+            Upgrade::ItemRate => "Supply Drops",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Upgrade::MaxHp => "+1 max HP and repair 1 HP",
+            Upgrade::WeaponDamage => "+1 damage for every weapon",
+            Upgrade::MoveSpeed => "+40 movement speed",
+            Upgrade::Invulnerability => "+0.25s invulnerability after a hit",
+            Upgrade::ComboRetention => "Keep 25% more combo when it breaks",
+            Upgrade::ItemRate => "Items spawn 15% more often",
+        }
+    }
+
+    fn base_cost(&self) -> u32 {
+        match self {
+            Upgrade::MaxHp => 4000,
+            Upgrade::WeaponDamage => 5000,
+            Upgrade::MoveSpeed => 2000,
+            Upgrade::Invulnerability => 2500,
+            Upgrade::ComboRetention => 3000,
+            Upgrade::ItemRate => 2000,
+        }
+    }
+
+    pub fn max_level(&self) -> u32 {
+        match self {
+            Upgrade::MaxHp => 5,
+            Upgrade::WeaponDamage => 3,
+            Upgrade::MoveSpeed => 4,
+            Upgrade::Invulnerability => 4,
+            Upgrade::ComboRetention => 3,
+            Upgrade::ItemRate => 4,
+        }
+    }
+
+    pub fn cost(&self, level: u32) -> u32 {
+        self.base_cost() * (level + 1)
+    }
+
+    fn apply(&self, ship: &mut Ship) {
+        match self {
+            Upgrade::MaxHp => {
+                ship.max_hp += 1;
+                ship.hp += 1;
+            }
+            Upgrade::WeaponDamage => ship.damage_bonus += 1,
+            Upgrade::MoveSpeed => ship.speed += 40.0,
+            Upgrade::Invulnerability => ship.invulnerability_bonus += 0.25,
+            Upgrade::ComboRetention => ship.combo_retention += 0.25,
+            // Read by the item spawner through Upgrades
+            Upgrade::ItemRate => {}
+        }
+    }
+}
+
+// Levels bought this run, indexed like Upgrade::ALL
+#[derive(Resource, Default)]
+pub struct Upgrades {
+    pub levels: [u32; 6],
+}
+
+impl Upgrades {
+    pub fn level(&self, upgrade: Upgrade) -> u32 {
+        self.levels[upgrade as usize]
+    }
+
+    pub fn item_interval_multiplier(&self) -> f32 {
+        0.85_f32.powi(self.level(Upgrade::ItemRate) as i32)
+    }
+}
+
+#[derive(Component)]
+struct ShopUi;
+
+#[derive(Component)]
+struct ShopItemText {
+    index: usize,
+}
+
+#[derive(Component)]
+struct ShopScoreText;
+
+fn reset_shop_selection(mut menu_selection: ResMut<MenuSelection>) {
+    *menu_selection = MenuSelection::new(Upgrade::ALL.len());
+}
+
+fn setup_shop(mut commands: Commands, wave: Res<crate::resources::Wave>) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.9)),
+        ShopUi,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(format!("WAVE {} INCOMING - UPGRADES", wave.current)),
+            TextFont {
+                font_size: 50.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.0, 0.8, 1.0)),
+            Node {
+                margin: UiRect::bottom(Val::Px(20.0)),
+                ..default()
+            },
+        ));
+
+        parent.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 30.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.84, 0.0)),
+            Node {
+                margin: UiRect::bottom(Val::Px(30.0)),
+                ..default()
+            },
+            ShopScoreText,
+        ));
+
+        for index in 0..Upgrade::ALL.len() {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 26.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                ShopItemText { index },
+            ));
+        }
+
+        parent.spawn((
+            Text::new("Use ↑↓ Arrow Keys to Select  |  ENTER to Buy  |  SPACE to Continue"),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            Node {
+                margin: UiRect::top(Val::Px(30.0)),
+                ..default()
+            },
+        ));
+    });
+}
+
+fn shop_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut menu_selection: ResMut<MenuSelection>,
+    mut upgrades: ResMut<Upgrades>,
+    mut player_query: Query<&mut Ship, With<Player>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::ArrowUp) {
+        menu_selection.move_up();
+    } else if keyboard_input.just_pressed(KeyCode::ArrowDown) {
+        menu_selection.move_down();
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Enter) {
+        let Ok(mut ship) = player_query.get_single_mut() else {
+            return;
+        };
+
+        let upgrade = Upgrade::ALL[menu_selection.selected_index];
+        let level = upgrades.level(upgrade);
+        let cost = upgrade.cost(level);
+
+        if level >= upgrade.max_level() {
+            println!("{} is already maxed out", upgrade.name());
+        } else if ship.score < cost {
+            println!("Not enough score for {} ({} needed)", upgrade.name(), cost);
+        } else {
+            ship.score -= cost;
+            upgrade.apply(&mut ship);
+            upgrades.levels[upgrade as usize] += 1;
+            println!("Bought {} (level {})", upgrade.name(), level + 1);
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        next_state.set(GameState::Running);
+    }
+}
+
+fn update_shop_text(
+    menu_selection: Res<MenuSelection>,
+    upgrades: Res<Upgrades>,
+    player_query: Query<&Ship, With<Player>>,
+    mut score_query: Query<&mut Text, (With<ShopScoreText>, Without<ShopItemText>)>,
+    mut item_query: Query<(&ShopItemText, &mut Text, &mut TextColor)>,
+) {
+    let Ok(ship) = player_query.get_single() else {
+        return;
+    };
+
+    if let Ok(mut text) = score_query.get_single_mut() {
+        text.0 = format!("Score to spend: {}", ship.score);
+    }
+
+    for (item, mut text, mut color) in item_query.iter_mut() {
+        let upgrade = Upgrade::ALL[item.index];
+        let level = upgrades.level(upgrade);
+        let selected = item.index == menu_selection.selected_index;
+        let marker = if selected { ">" } else { " " };
+
+        if level >= upgrade.max_level() {
+            text.0 = format!("{} {} [MAX] - {}", marker, upgrade.name(), upgrade.description());
+            color.0 = Color::srgb(0.5, 0.5, 0.5);
+            continue;
+        }
+
+        let cost = upgrade.cost(level);
+        text.0 = format!(
+            "{} {} [{}/{}] - {} - {} pts",
+            marker,
+            upgrade.name(),
+            level,
+            upgrade.max_level(),
+            upgrade.description(),
+            cost
+        );
+        color.0 = match (selected, ship.score >= cost) {
+            (true, true) => Color::srgb(0.0, 1.0, 0.0),
+            (true, false) => Color::srgb(1.0, 0.3, 0.3),
+            (false, true) => Color::WHITE,
+            (false, false) => Color::srgb(0.6, 0.6, 0.6),
+        };
+    }
+}
+
+fn cleanup_shop(mut commands: Commands, query: Query<Entity, With<ShopUi>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -6,7 +6,10 @@ pub struct GameUiPlugin;
 impl Plugin for GameUiPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, (setup_background, setup_ui))
-           .add_systems(Update, (animate_background, update_ui, update_typing_input).run_if(in_state(crate::resources::GameState::Running)))
+           .add_systems(Update, (animate_background, update_ui, update_weapon_text, update_typing_input, update_energy_bar).run_if(in_state(crate::resources::GameState::Running)))
+           .configure_sets(Update, TypingSubmit.after(update_typing_input))
+           .add_systems(OnEnter(crate::resources::GameState::Running), enable_ime)
+           .add_systems(OnExit(crate::resources::GameState::Running), disable_ime)
            .init_resource::<TypingBuffer>();
     }
 }
@@ -32,12 +35,68 @@ struct WaveText;
 struct WeaponText;
 
 
+#[derive(Component)]
+struct EnergyBar;
+
+#[derive(Component)]
+struct EnergyFill;
+
 #[derive(Component)]
 struct TypingInputBox;
 
+#[derive(Component)]
+struct TypingPreedit;
+
+// Every system that submits the buffer on Enter, so they all see this frame's IME commit
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypingSubmit;
+
 #[derive(Resource, Default)]
 pub struct TypingBuffer {
     pub text: String,
+    // Uncommitted IME composition, shown in the input box but never submitted
+    pub preedit: String,
+    // Set for the frame in which Enter only committed the composition
+    enter_committed: bool,
+    // Backspaces and characters that left no on-screen target matching, for the current word
+    pub mistakes: u32,
+    // Elapsed seconds when the first character of the current word went in
+    pub started_at: Option<f32>,
+}
+
+impl TypingBuffer {
+    pub fn is_composing(&self) -> bool {
+        !self.preedit.is_empty()
+    }
+    
+    // Enter belongs to the IME while it's composing, and the commit it triggers
+    // clears the preedit before the submit systems get to look
+    pub fn enter_commits(&self) -> bool {
+        self.is_composing() || self.enter_committed
+    }
+    
+    pub fn is_clean(&self) -> bool {
+        self.mistakes == 0
+    }
+    
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.mistakes = 0;
+        self.started_at = None;
+    }
+    
+    pub fn typing_secs(&self, now: f32) -> Option<f32> {
+        self.started_at.map(|start| now - start)
+    }
+    
+    fn push_checked(&mut self, c: char, targets: &[String], easy: bool) {
+        self.text.push(c);
+        
+        let typed = if easy { self.text.to_lowercase() } else { self.text.clone() };
+        if !targets.iter().any(|t| t.starts_with(&typed)) {
+            self.mistakes += 1;
+        }
+    }
 }
 
 fn setup_ui(mut commands: Commands) {
@@ -121,6 +180,29 @@ fn setup_ui(mut commands: Commands) {
         WeaponText,
     ));
 
+    commands.spawn((
+        Node {
+            width: Val::Px(150.0),
+            height: Val::Px(16.0),
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(18.0),
+            left: Val::Px(260.0),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK),
+        BorderColor(Color::srgb(1.0, 0.0, 1.0)),
+        EnergyBar,
+    )).with_child((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.0, 0.8, 1.0)),
+        EnergyFill,
+    ));
+
     commands.spawn((
         Text::new(""),
         TextFont {
@@ -144,6 +226,14 @@ fn setup_ui(mut commands: Commands) {
         },
         BackgroundColor(Color::srgba(0.2, 0.2, 0.2, 0.8)),
         TypingInputBox,
+    )).with_child((
+        TextSpan::default(),
+        TextFont {
+            font_size: 35.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.6, 0.6, 0.6)),
+        TypingPreedit,
     ));
 }
 
@@ -152,9 +242,8 @@ fn update_ui(
     wave_res: Res<crate::resources::Wave>,
     mut score_query: Query<&mut Text, (With<ScoreText>, Without<HpText>, Without<ComboText>, Without<WaveText>, Without<WeaponText>)>,
     mut hp_query: Query<&mut Text, (With<HpText>, Without<ScoreText>, Without<ComboText>, Without<WaveText>, Without<WeaponText>)>,
-    mut combo_query: Query<&mut Text, (With<ComboText>, Without<ScoreText>, Without<HpText>, Without<WaveText>, Without<WeaponText>)>,
+    mut combo_query: Query<(&mut Text, &mut TextColor), (With<ComboText>, Without<ScoreText>, Without<HpText>, Without<WaveText>, Without<WeaponText>)>,
     mut wave_query: Query<&mut Text, (With<WaveText>, Without<ScoreText>, Without<HpText>, Without<ComboText>, Without<WeaponText>)>,
-    mut weapon_query: Query<&mut Text, (With<WeaponText>, Without<ScoreText>, Without<HpText>, Without<ComboText>, Without<WaveText>)>,
 ) {
     if let Ok(ship) = player_query.get_single() {
         if let Ok(mut text) = score_query.get_single_mut() {
@@ -162,15 +251,68 @@ fn update_ui(
         }
         if let Ok(mut text) = hp_query.get_single_mut() {
             text.0 = format!("HP: {}", ship.hp);
+            if ship.shield > 0 {
+                text.0 += &format!("  Shield: {}", ship.shield);
+            }
         }
-        if let Ok(mut text) = combo_query.get_single_mut() {
+        if let Ok((mut text, mut color)) = combo_query.get_single_mut() {
+            let tier = ship.combo_tier();
             text.0 = format!("Combo: {}", ship.combo);
+            if tier != crate::combo::ComboTier::None {
+                text.0 += &format!(" {}", tier.name());
+            }
+            if ship.combo > 0 {
+                text.0 += &format!(" ({:.1}s)", ship.combo_timer.remaining_secs());
+            }
+            if ship.combo_shield {
+                text.0 += " [SHIELD]";
+            }
+            if ship.perfect_streak > 0 {
+                text.0 += &format!("  Perfect x{:.1}", ship.perfect_multiplier());
+            }
+            color.0 = tier.color();
         }
         if let Ok(mut text) = wave_query.get_single_mut() {
             text.0 = format!("Wave: {}", wave_res.current);
         }
-        if let Ok(mut text) = weapon_query.get_single_mut() {
-            text.0 = format!("Weapon: {:?}", ship.current_weapon);
+    }
+}
+
+fn update_weapon_text(
+    player_query: Query<&Ship, With<Player>>,
+    weapons: Res<crate::combat::WeaponRegistry>,
+    mut weapon_query: Query<&mut Text, With<WeaponText>>,
+) {
+    if let (Ok(ship), Ok(mut text)) = (player_query.get_single(), weapon_query.get_single_mut()) {
+        text.0 = format!("Weapon: {}", weapons.get(ship.current_weapon).name);
+        if ship.is_locked_out() {
+            text.0 += " (LOCKED)";
+        }
+    }
+}
+
+fn update_energy_bar(
+    player_query: Query<&Ship, With<Player>>,
+    weapons: Res<crate::combat::WeaponRegistry>,
+    mut bar_query: Query<&mut Visibility, With<EnergyBar>>,
+    mut fill_query: Query<(&mut Node, &mut BackgroundColor), With<EnergyFill>>,
+) {
+    if let Ok(ship) = player_query.get_single() {
+        if let Ok(mut visibility) = bar_query.get_single_mut() {
+            *visibility = if weapons.get(ship.current_weapon).uses_energy() {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+        }
+        
+        if let Ok((mut node, mut color)) = fill_query.get_single_mut() {
+            node.width = Val::Percent(ship.energy / ship.max_energy * 100.0);
+            color.0 = if ship.is_overheated() {
+                Color::srgb(1.0, 0.2, 0.0)
+            } else {
+                Color::srgb(0.0, 0.8, 1.0)
+            };
         }
     }
 }
@@ -219,39 +361,135 @@ fn animate_background(
 }
 
 use bevy::input::keyboard::{KeyboardInput, Key};
+use bevy::window::{Ime, PrimaryWindow};
+
+// Everything a keystroke could be heading towards, for judging it as it's typed
+#[derive(bevy::ecs::system::SystemParam)]
+struct TypingTargets<'w, 's> {
+    word_query: Query<'w, 's, &'static crate::enemy::Word>,
+    boss_line_query: Query<'w, 's, &'static crate::boss::BossLine>,
+    bullet_query: Query<'w, 's, &'static crate::enemy::EnemyBullet>,
+    bomb_command: Res<'w, crate::bomb::BombCommand>,
+    difficulty: Res<'w, crate::resources::Difficulty>,
+}
+
+impl TypingTargets<'_, '_> {
+    fn easy(&self) -> bool {
+        *self.difficulty == crate::resources::Difficulty::Easy
+    }
+
+    // Lowercased on Easy, where case doesn't count
+    fn collect(&self) -> Vec<String> {
+        let easy = self.easy();
+        self.word_query
+            .iter()
+            .map(|word| word.text.clone())
+            .chain(self.boss_line_query.iter().filter_map(|line| line.lines.get(line.current_line_index).cloned()))
+            .chain(self.bullet_query.iter().map(|bullet| bullet.word.clone()))
+            .chain(std::iter::once(self.bomb_command.word().to_string()))
+            .map(|t| if easy { t.to_lowercase() } else { t })
+            .collect()
+    }
+}
 
 fn update_typing_input(
     mut key_evr: EventReader<KeyboardInput>,
+    mut ime_evr: EventReader<Ime>,
     mut typing_buffer: ResMut<TypingBuffer>,
     mut query: Query<(&mut Text, &mut TextFont), With<TypingInputBox>>,
+    mut preedit_query: Query<&mut TextSpan, With<TypingPreedit>>,
+    typing_targets: TypingTargets,
+    time: Res<Time>,
 ) {
+    let easy = typing_targets.easy();
+    let targets = typing_targets.collect();
+    let was_composing = typing_buffer.is_composing();
+    typing_buffer.enter_committed = false;
+    
+    for ev in ime_evr.read() {
+        match ev {
+            Ime::Preedit { value, .. } => {
+                typing_buffer.preedit = value.clone();
+            }
+            Ime::Commit { value, .. } => {
+                for c in value.chars() {
+                    if !c.is_control() {
+                        typing_buffer.push_checked(c, &targets, easy);
+                    }
+                }
+                typing_buffer.preedit.clear();
+            }
+            Ime::Disabled { .. } => {
+                typing_buffer.preedit.clear();
+            }
+            Ime::Enabled { .. } => {}
+        }
+    }
+    
     for ev in key_evr.read() {
         if !ev.state.is_pressed() {
             continue;
         }
         
+        // While composing, the IME owns the keyboard until it commits
+        if was_composing || typing_buffer.is_composing() {
+            if ev.logical_key == Key::Enter {
+                typing_buffer.enter_committed = true;
+            }
+            continue;
+        }
+        
         match &ev.logical_key {
             Key::Character(s) => {
                 for c in s.chars() {
                     if !c.is_control() {
-                        typing_buffer.text.push(c);
+                        typing_buffer.push_checked(c, &targets, easy);
                     }
                 }
             }
             Key::Space => {
-                typing_buffer.text.push(' ');
+                typing_buffer.push_checked(' ', &targets, easy);
             }
             
-            Key::Backspace => {
+            Key::Backspace if !typing_buffer.text.is_empty() => {
                 typing_buffer.text.pop();
+                typing_buffer.mistakes += 1;
             }
             _ => {}
         }
     }
     
+    if typing_buffer.text.is_empty() {
+        typing_buffer.started_at = None;
+    } else if typing_buffer.started_at.is_none() {
+        typing_buffer.started_at = Some(time.elapsed_secs());
+    }
+    
     if let Ok((mut text, mut font)) = query.get_single_mut() {
         text.0 = typing_buffer.text.clone();
         
         font.font_size = 35.0;
     }
+    
+    if let Ok(mut span) = preedit_query.get_single_mut() {
+        span.0 = typing_buffer.preedit.clone();
+    }
+}
+
+fn enable_ime(mut window_query: Query<&mut Window, With<PrimaryWindow>>) {
+    if let Ok(mut window) = window_query.get_single_mut() {
+        window.ime_enabled = true;
+        // Keep the candidate popup just above the typing box
+        window.ime_position = Vec2::new(window.width() / 2.0, window.height() - 120.0);
+    }
+}
+
+fn disable_ime(
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    mut typing_buffer: ResMut<TypingBuffer>,
+) {
+    if let Ok(mut window) = window_query.get_single_mut() {
+        window.ime_enabled = false;
+    }
+    typing_buffer.preedit.clear();
 }
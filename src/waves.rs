@@ -0,0 +1,316 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use crate::enemy::{EnemyArchetype, EnemyRegistry};
+use crate::formation::Formation;
+
+pub struct WavePlugin;
+
+impl Plugin for WavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WaveScript>();
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DropDef {
+    // Seconds between item drops, before shop upgrades
+    pub interval: f32,
+    // Chance that a drop is a bomb rather than health
+    pub bomb_chance: f64,
+}
+
+impl Default for DropDef {
+    fn default() -> Self {
+        Self {
+            interval: 17.0,
+            bomb_chance: 0.25,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WaveDef {
+    pub count: usize,
+    // Seconds between spawns
+    pub interval: f32,
+    // (archetype, weight) pairs; empty uses every archetype's spawn_weight
+    #[serde(default)]
+    pub mix: Vec<(String, u32)>,
+    // Formations the wave may use, each spawn rolling formation_chance
+    #[serde(default)]
+    pub formations: Vec<Formation>,
+    #[serde(default)]
+    pub formation_chance: f64,
+    // Narrows every archetype's word lengths for this wave
+    #[serde(default)]
+    pub word_length: Option<(usize, usize)>,
+    #[serde(default)]
+    pub drops: DropDef,
+    #[serde(default)]
+    pub boss: bool,
+}
+
+// Anything outside these falls back to the default with a warning
+const DEFAULT_INTERVAL: f32 = 2.0;
+
+fn positive_or(value: f32, fallback: f32, what: &str) -> f32 {
+    if value.is_finite() && value > 0.0 {
+        value
+    } else {
+        println!("Warning: {} {} must be a positive number, using {}", what, value, fallback);
+        fallback
+    }
+}
+
+fn chance_or(value: f64, fallback: f64, what: &str) -> f64 {
+    if (0.0..=1.0).contains(&value) {
+        value
+    } else {
+        println!("Warning: {} {} must be between 0 and 1, using {}", what, value, fallback);
+        fallback
+    }
+}
+
+impl WaveDef {
+    // Values that would stall the wave or panic a timer are replaced
+    fn validate(&mut self, name: &str) {
+        self.interval = positive_or(self.interval, DEFAULT_INTERVAL, &format!("{} interval", name));
+        self.formation_chance = chance_or(self.formation_chance, 0.0, &format!("{} formation_chance", name));
+        if self.count == 0 && !self.boss {
+            println!("Warning: {} has no enemies, using 1", name);
+            self.count = 1;
+        }
+
+        let drops = DropDef::default();
+        self.drops.interval = positive_or(self.drops.interval, drops.interval, &format!("{} drop interval", name));
+        self.drops.bomb_chance = chance_or(self.drops.bomb_chance, drops.bomb_chance, &format!("{} bomb_chance", name));
+    }
+
+    // Weighted by the wave's mix; names the registry doesn't know are skipped
+    pub fn pick<'a>(&self, registry: &'a EnemyRegistry, rng: &mut impl Rng) -> &'a EnemyArchetype {
+        let known: Vec<(&EnemyArchetype, u32)> = self
+            .mix
+            .iter()
+            .filter_map(|(name, weight)| registry.get(name).map(|archetype| (archetype, *weight)))
+            .collect();
+
+        let total: u32 = known.iter().map(|(_, weight)| weight).sum();
+        if total == 0 {
+            return registry.pick(rng);
+        }
+
+        let mut roll = rng.gen_range(0..total);
+        for (archetype, weight) in &known {
+            if roll < *weight {
+                return archetype;
+            }
+            roll -= weight;
+        }
+
+        registry.pick(rng)
+    }
+
+    // The archetype with its word lengths clamped to the wave's band
+    pub fn shape(&self, archetype: &EnemyArchetype) -> EnemyArchetype {
+        let mut shaped = archetype.clone();
+        if let Some((min, max)) = self.word_length {
+            let (low, high) = archetype.word_length;
+            shaped.word_length = if low.max(min) <= high.min(max) {
+                (low.max(min), high.min(max))
+            } else {
+                (min, max)
+            };
+        }
+        shaped
+    }
+
+    pub fn formation(&self, rng: &mut impl Rng) -> Option<Formation> {
+        if self.formations.is_empty() || !rng.gen_bool(self.formation_chance.clamp(0.0, 1.0)) {
+            return None;
+        }
+        Some(self.formations[rng.gen_range(0..self.formations.len())])
+    }
+}
+
+// Waves past the end of the script are built from the template, growing a
+// little each time
+#[derive(Debug, Clone, Deserialize)]
+pub struct EndlessRule {
+    pub template: WaveDef,
+    pub count_step: usize,
+    pub interval_step: f32,
+    pub min_interval: f32,
+    // Every wave number divisible by this is a boss wave; 0 for none
+    pub boss_every: usize,
+}
+
+const DEFAULT_SCRIPT: &str = include_str!("../data/waves.ron");
+
+#[derive(Resource, Debug, Clone, Deserialize)]
+pub struct WaveScript {
+    pub waves: Vec<WaveDef>,
+    pub endless: EndlessRule,
+}
+
+impl Default for WaveScript {
+    fn default() -> Self {
+        Self::load_from_file()
+    }
+}
+
+impl WaveScript {
+    pub fn load_from_file() -> Self {
+        let path = "data/waves.ron";
+
+        let script = match std::fs::read_to_string(path) {
+            Ok(content) => match ron::from_str::<WaveScript>(&content) {
+                Ok(script) => script.validated(),
+                Err(e) => {
+                    println!("Warning: Failed to parse {}: {}, using defaults", path, e);
+                    Self::default_script()
+                }
+            },
+            Err(_) => {
+                println!("Warning: No wave file found, using default waves");
+                Self::default_script()
+            }
+        };
+
+        println!("Loaded {} scripted waves", script.waves.len());
+        script
+    }
+
+    // The shipped file, built in so a broken or missing copy still has something to fall back on
+    fn default_script() -> Self {
+        ron::from_str::<WaveScript>(DEFAULT_SCRIPT).expect("built-in waves.ron should parse").validated()
+    }
+
+    fn validated(mut self) -> Self {
+        for (index, def) in self.waves.iter_mut().enumerate() {
+            def.validate(&format!("Wave {}", index + 1));
+        }
+
+        let rule = &mut self.endless;
+        rule.template.validate("Endless template");
+        rule.min_interval = positive_or(rule.min_interval, DEFAULT_INTERVAL, "Endless min_interval");
+        if !rule.interval_step.is_finite() {
+            println!("Warning: Endless interval_step {} must be a number, using 0", rule.interval_step);
+            rule.interval_step = 0.0;
+        }
+
+        self
+    }
+
+    // Wave numbers start at 1
+    pub fn wave(&self, number: usize) -> WaveDef {
+        if let Some(def) = self.waves.get(number.saturating_sub(1)) {
+            return def.clone();
+        }
+
+        let rule = &self.endless;
+        let past_end = number - self.waves.len();
+        let mut def = rule.template.clone();
+        def.count += past_end * rule.count_step;
+        def.interval = (def.interval - past_end as f32 * rule.interval_step).max(rule.min_interval);
+        def.boss = rule.boss_every > 0 && number.is_multiple_of(rule.boss_every);
+        def
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wave_def(count: usize, interval: f32) -> WaveDef {
+        WaveDef {
+            count,
+            interval,
+            mix: Vec::new(),
+            formations: Vec::new(),
+            formation_chance: 0.0,
+            word_length: None,
+            drops: DropDef::default(),
+            boss: false,
+        }
+    }
+
+    fn script() -> WaveScript {
+        WaveScript {
+            waves: vec![wave_def(5, 2.0), wave_def(8, 1.5)],
+            endless: EndlessRule {
+                template: wave_def(10, 1.2),
+                count_step: 2,
+                interval_step: 0.1,
+                min_interval: 0.9,
+                boss_every: 5,
+            },
+        }
+    }
+
+    // Validation only ever replaces bad values, so a clean file comes through it untouched
+    fn assert_unchanged(before: &WaveDef, after: &WaveDef, name: &str) {
+        assert_eq!(before.count, after.count, "{} count", name);
+        assert_eq!(before.interval, after.interval, "{} interval", name);
+        assert_eq!(before.formation_chance, after.formation_chance, "{} formation_chance", name);
+        assert_eq!(before.drops.interval, after.drops.interval, "{} drop interval", name);
+        assert_eq!(before.drops.bomb_chance, after.drops.bomb_chance, "{} bomb_chance", name);
+    }
+
+    #[test]
+    fn built_in_waves_parse_and_pass_validation() {
+        let parsed: WaveScript = ron::from_str(DEFAULT_SCRIPT).expect("waves.ron should parse");
+        let validated = parsed.clone().validated();
+
+        for (index, (before, after)) in parsed.waves.iter().zip(&validated.waves).enumerate() {
+            assert_unchanged(before, after, &format!("Wave {}", index + 1));
+        }
+        assert_unchanged(&parsed.endless.template, &validated.endless.template, "Endless template");
+        assert_eq!(parsed.endless.min_interval, validated.endless.min_interval);
+        assert_eq!(parsed.endless.interval_step, validated.endless.interval_step);
+
+        // Unknown names in a mix are silently skipped at spawn time, so catch typos here
+        let archetypes: Vec<crate::enemy::EnemyArchetype> =
+            ron::from_str(include_str!("../data/enemies.ron")).expect("enemies.ron should parse");
+        let registry = EnemyRegistry { archetypes };
+        for def in parsed.waves.iter().chain([&parsed.endless.template]) {
+            for (name, _) in &def.mix {
+                assert!(registry.get(name).is_some(), "unknown enemy {} in a wave mix", name);
+            }
+        }
+    }
+
+    #[test]
+    fn scripted_waves_come_back_unchanged() {
+        let script = script();
+        assert_eq!(script.wave(1).count, 5);
+        assert_eq!(script.wave(2).count, 8);
+        assert_eq!(script.wave(2).interval, 1.5);
+    }
+
+    #[test]
+    fn endless_waves_grow_past_the_script() {
+        let script = script();
+        assert_eq!(script.wave(3).count, 12);
+        assert!((script.wave(3).interval - 1.1).abs() < 1e-5);
+        assert_eq!(script.wave(6).count, 18);
+    }
+
+    #[test]
+    fn endless_interval_stops_at_the_minimum() {
+        let script = script();
+        assert_eq!(script.wave(20).interval, 0.9);
+        assert_eq!(script.wave(100).interval, 0.9);
+    }
+
+    #[test]
+    fn endless_boss_waves_follow_boss_every() {
+        let mut script = script();
+        assert!(script.wave(5).boss);
+        assert!(script.wave(10).boss);
+        assert!(!script.wave(6).boss);
+
+        script.endless.boss_every = 0;
+        assert!(!script.wave(10).boss);
+    }
+}